@@ -0,0 +1,94 @@
+use std::fmt;
+
+/// What a part returns: a number, some text (e.g. day10's CRT picture), or a
+/// marker for parts that have no answer yet.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// The solver ran but the input has no solution, e.g. no path in day12.
+    NoSolution,
+    /// The part has not been solved yet.
+    Unimplemented,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::NoSolution => write!(f, "no solution"),
+            Answer::Unimplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(number: $number) -> Self {
+                    Answer::Number(number.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(number: usize) -> Self {
+        Answer::Number(number as i128)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(number: u128) -> Self {
+        // only answers past i128::MAX fall back to their decimal text
+        i128::try_from(number).map_or_else(|_| Answer::Text(number.to_string()), Answer::Number)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Self {
+        answer.map_or(Answer::NoSolution, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(42_u32).to_string(), "42");
+        assert_eq!(Answer::from(-3_i32).to_string(), "-3");
+        assert_eq!(Answer::from("BSDMQFLSP").to_string(), "BSDMQFLSP");
+        assert_eq!(Answer::Unimplemented.to_string(), "not implemented");
+    }
+
+    #[test]
+    fn test_from_option() {
+        assert_eq!(Answer::from(Some(31_u32)), Answer::Number(31));
+        assert_eq!(Answer::from(None::<u32>), Answer::NoSolution);
+    }
+
+    #[test]
+    fn test_from_u128() {
+        assert_eq!(Answer::from(2713310158_u128), Answer::Number(2713310158));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+    }
+}
@@ -0,0 +1,209 @@
+//! Cycle detection for simulations asked about far more ticks than can be
+//! run one at a time, e.g. the monkeys of day11 after a billion rounds.
+//!
+//! [`find_cycle`] runs a [`State`] until a hashable key of it repeats. The
+//! key leaves out counters that only ever grow, such as inspection counts,
+//! which [`History::extrapolate`] then carries forward to any tick from how
+//! much they grow over one period.
+
+use std::{collections::HashMap, hash::Hash};
+
+use crate::{math::Integer, simulation::State};
+
+/// Where a sequence of states starts repeating: the state after
+/// `offset + period` ticks has the same key as the one after `offset`, and
+/// from there on the keys repeat every `period` ticks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Ticks before the first state that repeats.
+    pub offset: usize,
+    /// Ticks between repeats, at least one.
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The tick before the end of the first period whose state has the same
+    /// key as the one after `tick` ticks.
+    pub fn reduce(&self, tick: usize) -> usize {
+        tick - self.laps(tick) * self.period
+    }
+
+    /// How many whole periods [`Cycle::reduce`] skips for `tick`.
+    pub fn laps(&self, tick: usize) -> usize {
+        tick.saturating_sub(self.offset) / self.period
+    }
+}
+
+/// The states seen up to the first repeat, enough to answer for any tick.
+#[derive(Debug, Clone)]
+pub struct History<S> {
+    cycle: Cycle,
+    // the state after every tick from 0 to `offset + period`
+    states: Vec<S>,
+}
+
+impl<S> History<S> {
+    pub fn cycle(&self) -> Cycle {
+        self.cycle
+    }
+
+    /// A state with the same key as the one after `tick` ticks. Counters
+    /// left out of the key are those of the first period; see
+    /// [`History::extrapolate`].
+    pub fn state_at(&self, tick: usize) -> &S {
+        &self.states[self.cycle.reduce(tick)]
+    }
+
+    /// The value `counter` has after `tick` ticks, given that it grows by the
+    /// same amount over every period once the cycle is entered. Overflows
+    /// like any arithmetic if that value does not fit.
+    pub fn extrapolate<C>(&self, tick: usize, mut counter: impl FnMut(&S) -> C) -> C
+    where
+        C: Integer + TryFrom<usize>,
+    {
+        let Cycle { offset, period } = self.cycle;
+        let laps = C::try_from(self.cycle.laps(tick))
+            .unwrap_or_else(|_| panic!("{} periods do not fit the counter", tick / period));
+        let growth = counter(&self.states[offset + period]) - counter(&self.states[offset]);
+        counter(self.state_at(tick)) + growth * laps
+    }
+}
+
+/// Runs `state` until `key` of the state after some tick matches that of an
+/// earlier one, hashing every key seen. Returns `None` if the state finishes
+/// first; runs forever if keys never repeat.
+pub fn find_cycle<S, K>(state: S, key: impl FnMut(&S) -> K) -> Option<History<S>>
+where
+    S: State,
+    K: Hash + Eq,
+{
+    find_cycle_within(state, key, usize::MAX).ok()
+}
+
+/// Like [`find_cycle`], but gives up after `ticks` ticks, when running on
+/// would cost more than the cycle could save. Returns the last state if no
+/// key repeats by then or the state finishes first.
+pub fn find_cycle_within<S, K>(
+    mut state: S,
+    mut key: impl FnMut(&S) -> K,
+    ticks: usize,
+) -> Result<History<S>, S>
+where
+    S: State,
+    K: Hash + Eq,
+{
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    loop {
+        let tick = states.len();
+        if let Some(offset) = seen.insert(key(&state), tick) {
+            states.push(state);
+            let cycle = Cycle {
+                offset,
+                period: tick - offset,
+            };
+            return Ok(History { cycle, states });
+        }
+        if tick == ticks {
+            return Err(state);
+        }
+        states.push(state.clone());
+        if !state.step() {
+            return Err(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // x -> x² + 1 mod 255 from 3, counting the steps taken
+    #[derive(Debug, Clone)]
+    struct Squares {
+        x: u32,
+        steps: u64,
+    }
+
+    impl State for Squares {
+        fn step(&mut self) -> bool {
+            self.x = (self.x * self.x + 1) % 255;
+            self.steps += 1;
+            true
+        }
+    }
+
+    fn brute_force(ticks: usize) -> Squares {
+        let mut state = Squares { x: 3, steps: 0 };
+        for _ in 0..ticks {
+            state.step();
+        }
+        state
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let history = find_cycle(Squares { x: 3, steps: 0 }, |state| state.x).unwrap();
+        // 3, 10, 101, 2, 5, 26, 167, 95, 101, ...
+        assert_eq!(
+            history.cycle(),
+            Cycle {
+                offset: 2,
+                period: 6
+            }
+        );
+        for tick in 0..50 {
+            assert_eq!(history.state_at(tick).x, brute_force(tick).x);
+            assert_eq!(history.extrapolate(tick, |state| state.steps), tick as u64);
+        }
+        assert_eq!(
+            history.state_at(1_000_000_000_000).x,
+            brute_force(history.cycle().reduce(1_000_000_000_000)).x
+        );
+    }
+
+    #[test]
+    fn test_reduce() {
+        let cycle = Cycle {
+            offset: 3,
+            period: 4,
+        };
+        assert_eq!(cycle.reduce(2), 2);
+        assert_eq!(cycle.reduce(6), 6);
+        assert_eq!(cycle.reduce(7), 3);
+        assert_eq!(cycle.laps(7), 1);
+        assert_eq!(cycle.reduce(1003), 3);
+        assert_eq!(cycle.laps(1003), 250);
+    }
+
+    #[test]
+    fn test_finished() {
+        #[derive(Debug, Clone)]
+        struct Countdown(u32);
+        impl State for Countdown {
+            fn step(&mut self) -> bool {
+                if self.0 == 0 {
+                    return false;
+                }
+                self.0 -= 1;
+                true
+            }
+        }
+        assert!(find_cycle(Countdown(5), |countdown| countdown.0).is_none());
+        assert_eq!(
+            find_cycle_within(Countdown(5), |countdown| countdown.0, 10)
+                .unwrap_err()
+                .0,
+            0
+        );
+    }
+
+    #[test]
+    fn test_find_cycle_within() {
+        let start = Squares { x: 3, steps: 0 };
+        // the first repeat is after 8 ticks
+        let last = find_cycle_within(start.clone(), |state| state.x, 7).unwrap_err();
+        assert_eq!((last.x, last.steps), (brute_force(7).x, 7));
+        assert!(find_cycle_within(start, |state| state.x, 8).is_ok());
+    }
+}
@@ -0,0 +1,252 @@
+use std::{fmt, io, path::PathBuf};
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Malformed input, pointing at where it went wrong.
+    #[error("{0}")]
+    Parse(ParseError),
+    /// Input that is wrong as a whole, e.g. a heightmap with no start.
+    #[error("invalid input: {0}")]
+    InvalidInput(String),
+    #[error("could not read input {}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// A [`ParseError`] pointing at `span`, a slice of `input`.
+    pub fn at(input: &str, span: &str, message: impl Into<String>) -> Self {
+        Error::Parse(ParseError::at(input, span, message))
+    }
+
+    /// Moves an error found while parsing `inner`, a slice of `outer`, to
+    /// where it is in `outer`. Errors without a position are left alone.
+    pub fn rebase(self, outer: &str, inner: &str) -> Self {
+        match self {
+            Error::Parse(err) => Error::Parse(err.rebase(outer, inner)),
+            err => err,
+        }
+    }
+
+    /// Records which day's input the error was found in.
+    pub fn for_day(self, day: u8) -> Self {
+        match self {
+            Error::Parse(err) => Error::Parse(ParseError {
+                day: Some(day),
+                ..err
+            }),
+            err => err,
+        }
+    }
+}
+
+/// Where an input went wrong: a span of one line, with what was expected
+/// there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The day whose input this is, once the runner knows it.
+    pub day: Option<u8>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// Width of the offending span in characters, at least one so an empty
+    /// span (a missing field) still gets a marker.
+    pub len: usize,
+    /// The whole line the span is on.
+    pub text: String,
+    pub message: String,
+    // byte offset of the span in the input it was located in
+    offset: usize,
+}
+
+impl ParseError {
+    /// Locates `span` in `input`. The span is expected to be a slice of
+    /// `input`, as returned by `lines`, `split` or `trim`; otherwise its
+    /// first occurrence is used, or the start of the input as a last resort.
+    pub fn at(input: &str, span: &str, message: impl Into<String>) -> Self {
+        let offset = offset_in(input, span)
+            .or_else(|| input.find(span).filter(|_| !span.is_empty()))
+            .unwrap_or(0);
+        let mut err = ParseError {
+            day: None,
+            line: 0,
+            column: 0,
+            len: span.chars().count().max(1),
+            text: String::new(),
+            message: message.into(),
+            offset,
+        };
+        err.locate(input);
+        err
+    }
+
+    /// See [`Error::rebase`].
+    pub fn rebase(mut self, outer: &str, inner: &str) -> Self {
+        if let Some(start) = offset_in(outer, inner) {
+            self.offset += start;
+            self.locate(outer);
+        }
+        self
+    }
+
+    /// The text the error points at.
+    pub fn span(&self) -> &str {
+        let start = self
+            .text
+            .char_indices()
+            .nth(self.column - 1)
+            .map_or(self.text.len(), |(index, _)| index);
+        let end = self.text[start..]
+            .char_indices()
+            .nth(self.len)
+            .map_or(self.text.len(), |(index, _)| start + index);
+        &self.text[start..end]
+    }
+
+    /// A multi-line diagnostic quoting the line and underlining the span:
+    ///
+    /// ```text
+    /// error: day 4: expected a number
+    ///  --> line 3, column 3
+    ///   |
+    /// 3 | 5-x,7-9
+    ///   |   ^
+    /// ```
+    pub fn render(&self) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        // keep tabs so the marker lines up however wide they are drawn
+        let indent: String = self
+            .text
+            .chars()
+            .chain(std::iter::repeat(' '))
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let mut rendered = String::from("error: ");
+        if let Some(day) = self.day {
+            rendered += &format!("day {}: ", day);
+        }
+        rendered += &format!(
+            "{}\n{}--> line {}, column {}\n{} |\n{} | {}\n{} | {}{}\n",
+            self.message,
+            gutter,
+            self.line,
+            self.column,
+            gutter,
+            number,
+            self.text,
+            gutter,
+            indent,
+            "^".repeat(self.len)
+        );
+        rendered
+    }
+
+    fn locate(&mut self, input: &str) {
+        let line_start = input[..self.offset]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        let line_end = input[self.offset..]
+            .find('\n')
+            .map_or(input.len(), |index| self.offset + index);
+        self.line = input[..line_start].matches('\n').count() + 1;
+        self.column = input[line_start..self.offset].chars().count() + 1;
+        self.text = input[line_start..line_end]
+            .trim_end_matches('\r')
+            .to_string();
+        // a span running past its line is cut at the line's end
+        let rest = (self.text.chars().count() + 1).saturating_sub(self.column);
+        self.len = self.len.min(rest.max(1));
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Byte offset of `inner` in `outer`, if it is a slice of it.
+fn offset_in(outer: &str, inner: &str) -> Option<usize> {
+    let start = (inner.as_ptr() as usize).checked_sub(outer.as_ptr() as usize)?;
+    (start + inner.len() <= outer.len() && outer.is_char_boundary(start)).then_some(start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let input = "1-2,3-4\n5-x,7-9\n";
+        let line = input.lines().nth(1).unwrap();
+        let err = ParseError::at(input, &line[2..3], "expected a number");
+        assert_eq!((err.line, err.column, err.len), (2, 3, 1));
+        assert_eq!(err.text, "5-x,7-9");
+        assert_eq!(err.span(), "x");
+        assert_eq!(err.to_string(), "line 2, column 3: expected a number");
+
+        // an empty span at the end of a line still points somewhere
+        let err = ParseError::at(input, &line[7..], "expected more");
+        assert_eq!((err.line, err.column, err.len), (2, 8, 1));
+        assert_eq!(err.span(), "");
+
+        // a span that is not a slice of the input is searched for
+        let err = ParseError::at(input, "7-9", "overlap");
+        assert_eq!((err.line, err.column, err.len), (2, 5, 3));
+    }
+
+    #[test]
+    fn test_rebase() {
+        let input = "Monkey 0:\n  Test: divisible by 23\n\nMonkey 1:\n  Test: divisible by x\n";
+        let block = input.split("\n\n").nth(1).unwrap();
+        let line = block.lines().nth(1).unwrap();
+        let err = Error::at(block, &line[21..], "expected a number");
+        let Error::Parse(err) = err.rebase(input, block).for_day(11) else {
+            panic!("not a parse error");
+        };
+        assert_eq!((err.day, err.line, err.column), (Some(11), 5, 22));
+        assert_eq!(err.span(), "x");
+        assert_eq!(
+            err.to_string(),
+            "day 11, line 5, column 22: expected a number"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let input = "5-x,7-99";
+        let err = ParseError::at(input, &input[4..], "expected a section");
+        assert_eq!(
+            err.render(),
+            "error: expected a section\n \
+             --> line 1, column 5\n  \
+             |\n\
+             1 | 5-x,7-99\n  \
+             |     ^^^^\n"
+        );
+
+        let input = "\tfoo\r\nbar";
+        let err = ParseError::at(input, &input[1..4], "tabs");
+        assert_eq!(err.text, "\tfoo");
+        assert!(err.render().ends_with("1 | \tfoo\n  | \t^^^\n"));
+    }
+}
@@ -0,0 +1,156 @@
+//! Building blocks for the random input generators each day has in its
+//! `generate` module: a small seeded [`Rng`] and the [`Generated`] input with
+//! whatever answers were cheap to know while building it.
+//!
+//! Generators write the exact puzzle format, so their output goes through the
+//! same parser as the real input, at whatever scale a test asks for.
+
+use std::ops::RangeInclusive;
+
+use crate::{Answer, Part, Solution};
+
+/// SplitMix64: fast, tiny and good enough for test data. The same seed gives
+/// the same numbers on every platform and in every version of this crate.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`. Panics if `n` is 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "no number is below 0");
+        // the high half of a 128-bit product spreads evenly enough over 0..n
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+
+    /// A number in `range`, both ends included.
+    pub fn between(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (low, high) = range.into_inner();
+        assert!(low <= high, "empty range {}..={}", low, high);
+        let span = high.abs_diff(low);
+        let offset = match span.checked_add(1) {
+            Some(len) => ((u128::from(self.next_u64()) * u128::from(len)) >> 64) as u64,
+            None => self.next_u64(),
+        };
+        low.wrapping_add(offset as i64)
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        // 53 random bits make a uniform float in [0, 1)
+        ((self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64) < p
+    }
+
+    /// One of `items`. Panics if there are none.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        // Fisher-Yates
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// A generated puzzle input, with the answers known by construction, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+impl Generated {
+    /// An input whose answers are only known by solving it.
+    pub fn new(input: String) -> Self {
+        Generated {
+            input,
+            part_one: None,
+            part_two: None,
+        }
+    }
+
+    pub fn with_answers(
+        mut self,
+        part_one: impl Into<Answer>,
+        part_two: impl Into<Answer>,
+    ) -> Self {
+        self.part_one = Some(part_one.into());
+        self.part_two = Some(part_two.into());
+        self
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part_one.as_ref(),
+            Part::Two => self.part_two.as_ref(),
+        }
+    }
+
+    /// For tests: panics unless `S` parses the input and finds every known
+    /// answer of the parts it implements.
+    pub fn assert_solved_by<S: Solution>(&self) {
+        let parsed = S::parse(&self.input)
+            .unwrap_or_else(|err| panic!("day {} rejects its own input: {}", S::DAY, err));
+        for &part in S::PARTS {
+            if let Some(expected) = self.answer(part) {
+                assert_eq!(
+                    &S::solve(&parsed, part),
+                    expected,
+                    "day {} part {}",
+                    S::DAY,
+                    part
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        // the same seed, the same numbers
+        let numbers: Vec<u64> = (0..3).map(|_| Rng::new(7).next_u64()).collect();
+        assert!(numbers.windows(2).all(|pair| pair[0] == pair[1]));
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+
+        let mut rng = Rng::new(1);
+        let mut seen = [0; 6];
+        for _ in 0..6000 {
+            seen[rng.below(6)] += 1;
+            let n = rng.between(-3..=3);
+            assert!((-3..=3).contains(&n));
+        }
+        assert!(
+            seen.iter().all(|&count| (800..1200).contains(&count)),
+            "{:?}",
+            seen
+        );
+        rng.between(i64::MIN..=i64::MAX);
+        assert_eq!(rng.between(5..=5), 5);
+
+        let mut items: Vec<u32> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
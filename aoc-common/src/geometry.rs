@@ -0,0 +1,392 @@
+//! 2D points and directions shared by the grid puzzles.
+//!
+//! The y axis points down, as in the puzzle drawings: [`Direction::Up`] is a
+//! step towards smaller `y`.
+
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::Error;
+
+/// An integer type points can be made of.
+pub trait Coordinate: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// `|self - other|`, without going below zero for unsigned types.
+    fn distance(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+/// A coordinate that can be negative, needed for steps and rotations.
+pub trait SignedCoordinate: Coordinate + Neg<Output = Self> {
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_coordinate {
+    ($($unsigned:ty),*; $($signed:ty),*) => {
+        $(
+            impl Coordinate for $unsigned {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+            }
+        )*
+        $(
+            impl Coordinate for $signed {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+            }
+
+            impl SignedCoordinate for $signed {
+                fn signum(self) -> Self {
+                    <$signed>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(u8, u16, u32, u64, u128, usize; i8, i16, i32, i64, i128, isize);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+
+    /// Converts both coordinates, e.g. a signed point to grid indices, or
+    /// `None` if either does not fit.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point<U>> {
+        Some(Point::new(
+            U::try_from(self.x).ok()?,
+            U::try_from(self.y).ok()?,
+        ))
+    }
+
+    /// Converts both coordinates to a type that holds every value of `T`.
+    pub fn cast<U: From<T>>(self) -> Point<U> {
+        Point::new(U::from(self.x), U::from(self.y))
+    }
+}
+
+impl<T: Coordinate> Point<T> {
+    pub const ORIGIN: Point<T> = Point::new(T::ZERO, T::ZERO);
+
+    /// Taxicab distance: the number of orthogonal steps between the points.
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    /// Chessboard distance: the number of king moves between the points.
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+}
+
+impl<T: SignedCoordinate> Point<T> {
+    /// Each coordinate replaced by its sign, turning a difference into a
+    /// single step of at most one on each axis.
+    pub fn signum(self) -> Self {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /// One step, diagonals included, from `self` towards `target`.
+    pub fn step_towards(self, target: Self) -> Self {
+        self + (target - self).signum()
+    }
+
+    /// Rotated a quarter turn clockwise as drawn, i.e. with y pointing down.
+    pub fn rotate_right(self) -> Self {
+        Point::new(-self.y, self.x)
+    }
+
+    /// Rotated a quarter turn counter-clockwise as drawn.
+    pub fn rotate_left(self) -> Self {
+        Point::new(self.y, -self.x)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Add<Output = T> + Copy> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Sub<Output = T> + Copy> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().turn_right().turn_right()
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// The unit step in this direction.
+    pub fn step<T: SignedCoordinate>(self) -> Point<T> {
+        match self {
+            Direction::Up => Point::new(T::ZERO, -T::ONE),
+            Direction::Right => Point::new(T::ONE, T::ZERO),
+            Direction::Down => Point::new(T::ZERO, T::ONE),
+            Direction::Left => Point::new(-T::ONE, T::ZERO),
+        }
+    }
+}
+
+/// Reads `U`, `R`, `D` or `L`, as in day9's motions.
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "U" => Ok(Direction::Up),
+            "R" => Ok(Direction::Right),
+            "D" => Ok(Direction::Down),
+            "L" => Ok(Direction::Left),
+            _ => Err(Error::InvalidInput(format!("unknown direction {:?}", s))),
+        }
+    }
+}
+
+/// Writes the letter [`Direction::from_str`] reads.
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letter = match self {
+            Direction::Up => "U",
+            Direction::Right => "R",
+            Direction::Down => "D",
+            Direction::Left => "L",
+        };
+        f.write_str(letter)
+    }
+}
+
+/// The smallest rectangle holding a set of points, edges included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds<T> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Coordinate> Bounds<T> {
+    /// The bounds of `points`, or `None` if there are none.
+    pub fn of(points: impl IntoIterator<Item = Point<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bounds = Bounds {
+            min: first,
+            max: first,
+        };
+        points.for_each(|point| bounds.extend(point));
+        Some(bounds)
+    }
+
+    /// Grows the bounds to hold `point`.
+    pub fn extend(&mut self, point: Point<T>) {
+        self.min = Point::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    pub fn contains(&self, point: Point<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 4);
+        assert_eq!(a + b, Point::new(2, 2));
+        assert_eq!(a - b, Point::new(4, -6));
+        assert_eq!(-a, Point::new(-3, 2));
+        assert_eq!(a * 3, Point::new(9, -6));
+        let mut c = a;
+        c += b;
+        c -= Point::new(2, 2);
+        assert_eq!(c, Point::ORIGIN);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(2, 18);
+        let b = Point::new(-2, 15);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        // unsigned points never underflow
+        assert_eq!(Point::new(1_u32, 5).manhattan(Point::new(4, 2)), 6);
+    }
+
+    #[test]
+    fn test_steps() {
+        assert_eq!(Point::new(-7, 0).signum(), Point::new(-1, 0));
+        assert_eq!(
+            Point::new(0, 0).step_towards(Point::new(2, -1)),
+            Point::new(1, -1)
+        );
+        assert_eq!(
+            Point::new(3, 3).step_towards(Point::new(3, 3)),
+            Point::new(3, 3)
+        );
+    }
+
+    #[test]
+    fn test_rotation() {
+        let up = Direction::Up.step::<i32>();
+        assert_eq!(up.rotate_right(), Direction::Right.step());
+        assert_eq!(up.rotate_left(), Direction::Left.step());
+        assert_eq!(
+            Point::new(2, 1).rotate_right().rotate_left(),
+            Point::new(2, 1)
+        );
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.step::<i32>().rotate_right(),
+                direction.turn_right().step()
+            );
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(-direction.step::<i64>(), direction.opposite().step());
+        }
+    }
+
+    #[test]
+    fn test_casts() {
+        assert_eq!(
+            Point::new(3_i32, 4).try_cast::<usize>(),
+            Some(Point::new(3, 4))
+        );
+        assert_eq!(Point::new(3_i32, -4).try_cast::<usize>(), None);
+        assert_eq!(Point::new(300_u32, 4).try_cast::<u8>(), None);
+        assert_eq!(Point::new(3_u8, 4).cast::<i64>(), Point::new(3, 4));
+        assert_eq!(<(i32, i32)>::from(Point::new(1, 2)), (1, 2));
+    }
+
+    #[test]
+    fn test_direction_from_str() {
+        assert_eq!("U".parse::<Direction>().unwrap(), Direction::Up);
+        assert_eq!("L".parse::<Direction>().unwrap(), Direction::Left);
+        assert!("X".parse::<Direction>().is_err());
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.to_string().parse::<Direction>().unwrap(),
+                direction
+            );
+        }
+    }
+
+    #[test]
+    fn test_bounds() {
+        let bounds =
+            Bounds::of([Point::new(498, 4), Point::new(503, 9), Point::new(500, 0)]).unwrap();
+        assert_eq!(bounds.min, Point::new(498, 0));
+        assert_eq!(bounds.max, Point::new(503, 9));
+        assert_eq!((bounds.width(), bounds.height()), (6, 10));
+        assert!(bounds.contains(Point::new(500, 9)));
+        assert!(!bounds.contains(Point::new(504, 9)));
+        assert_eq!(Bounds::<i32>::of([]), None);
+    }
+}
@@ -0,0 +1,176 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{Error, Result, DEFAULT_YEAR};
+
+/// Environment variable overriding the directory the default inputs live in.
+pub const INPUTS_DIR_ENV: &str = "AOC_INPUTS_DIR";
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `inputs/dayNN.txt` in the inputs directory.
+    Default,
+    /// The puzzle's worked example, `inputs/examples/dayNN.txt`.
+    Example,
+    /// An explicit file, e.g. a teammate's input.
+    Path(PathBuf),
+    /// Standard input, selected with `-` on the command line.
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads a `--input` argument: `-` means stdin, anything else is a path.
+    pub fn from_arg(arg: Option<&Path>) -> Self {
+        match arg {
+            None => InputSource::Default,
+            Some(path) if path == Path::new("-") => InputSource::Stdin,
+            Some(path) => InputSource::Path(path.to_path_buf()),
+        }
+    }
+}
+
+/// Loads puzzle inputs, falling back to one file per day in an inputs
+/// directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Inputs { dir: dir.into() }
+    }
+
+    /// Uses `$AOC_INPUTS_DIR` if set, the workspace's `inputs/` otherwise.
+    pub fn from_env() -> Self {
+        match env::var_os(INPUTS_DIR_ENV) {
+            Some(dir) => Inputs::new(dir),
+            None => Inputs::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs")),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The inputs of the `year` event. Those of [`DEFAULT_YEAR`] sit in the
+    /// inputs directory itself, other years in a subdirectory, e.g. `2023/`.
+    pub fn year(&self, year: u16) -> Self {
+        if year == DEFAULT_YEAR {
+            self.clone()
+        } else {
+            Inputs::new(self.dir.join(year.to_string()))
+        }
+    }
+
+    /// Path of a day's default input, e.g. `inputs/day07.txt`.
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{:02}.txt", day))
+    }
+
+    /// Path of a day's example input, e.g. `inputs/examples/day07.txt`.
+    pub fn example_path(&self, day: u8) -> PathBuf {
+        self.dir.join("examples").join(format!("day{:02}.txt", day))
+    }
+
+    /// Reads a day's input from `source` and normalizes it.
+    pub fn load(&self, day: u8, source: &InputSource) -> Result<String> {
+        let raw = match source {
+            InputSource::Default => read_file(&self.path(day))?,
+            InputSource::Example => read_file(&self.example_path(day))?,
+            InputSource::Path(path) => read_file(path)?,
+            InputSource::Stdin => {
+                let mut raw = String::new();
+                io::stdin()
+                    .read_to_string(&mut raw)
+                    .map_err(|source| Error::Io {
+                        path: PathBuf::from("-"),
+                        source,
+                    })?;
+                raw
+            }
+        };
+        Ok(normalize(&raw))
+    }
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Turns CRLF line endings into LF and drops trailing newlines, so parsers
+/// see the same text whichever editor or OS saved the input.
+pub fn normalize(raw: &str) -> String {
+    raw.replace("\r\n", "\n")
+        .trim_end_matches(['\n', '\r'])
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            normalize("1000\r\n2000\r\n\r\n3000\r\n"),
+            "1000\n2000\n\n3000"
+        );
+        assert_eq!(normalize("noop\naddx 3\n\n\n"), "noop\naddx 3");
+        // leading and inner whitespace is part of the puzzle, e.g. day5's drawing
+        assert_eq!(normalize("    [D]    \n 1 \n"), "    [D]    \n 1 ");
+    }
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg(None), InputSource::Default);
+        assert_eq!(
+            InputSource::from_arg(Some(Path::new("-"))),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::from_arg(Some(Path::new("alice/day11.txt"))),
+            InputSource::Path(PathBuf::from("alice/day11.txt"))
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(dir.join("examples")).unwrap();
+        fs::write(dir.join("day01.txt"), "1000\r\n2000\r\n").unwrap();
+        fs::write(dir.join("examples/day01.txt"), "1000\n\n4000\n").unwrap();
+        let inputs = Inputs::new(&dir);
+
+        assert_eq!(inputs.path(1), dir.join("day01.txt"));
+        assert_eq!(inputs.load(1, &InputSource::Default).unwrap(), "1000\n2000");
+        assert_eq!(
+            inputs
+                .load(1, &InputSource::Path(dir.join("day01.txt")))
+                .unwrap(),
+            "1000\n2000"
+        );
+        assert_eq!(
+            inputs.load(1, &InputSource::Example).unwrap(),
+            "1000\n\n4000"
+        );
+        assert!(matches!(
+            inputs.load(2, &InputSource::Default),
+            Err(Error::Io { .. })
+        ));
+        assert_eq!(inputs.year(DEFAULT_YEAR), inputs);
+        assert_eq!(
+            inputs.year(2023).example_path(1),
+            dir.join("2023/examples/day01.txt")
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,25 @@
+//! Pieces shared by every day: the [`Solution`] trait the runner dispatches
+//! to, the [`Answer`] a part returns, the common [`Error`] type with its
+//! [`parse`] helpers, the [`input`] loader, the 2D [`geometry`] and shortest
+//! path [`search`] the grid puzzles share, the number theory in [`math`] and
+//! the tick-by-tick [`simulation`] driver with [`cycle`] detection to
+//! fast-forward it, the seeded [`generate`] helpers behind every day's
+//! input generator, and the [`parallel`] map that the `parallel` feature
+//! spreads over threads.
+
+mod answer;
+pub mod cycle;
+mod error;
+pub mod generate;
+pub mod geometry;
+pub mod input;
+pub mod math;
+pub mod parallel;
+pub mod parse;
+pub mod search;
+pub mod simulation;
+mod solution;
+
+pub use answer::Answer;
+pub use error::{Error, ParseError, Result};
+pub use solution::{Part, Registered, Solution, SolvePart, DEFAULT_YEAR};
@@ -0,0 +1,270 @@
+//! Number theory for the puzzles about cycles and periods: gcd and lcm,
+//! modular arithmetic and the Chinese remainder theorem.
+//!
+//! Everything is generic over the primitive integers and avoids overflowing
+//! on the way to a result that fits.
+
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+use thiserror::Error;
+
+/// A primitive integer type.
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// The absolute value, the identity for unsigned types.
+    fn magnitude(self) -> Self;
+}
+
+/// An integer type with negative values, needed for Bézout coefficients.
+pub trait SignedInteger: Integer + Neg<Output = Self> {
+    /// The remainder in `0..modulus`, whatever the sign of `self`.
+    fn rem_euclid(self, modulus: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($unsigned:ty),*; $($signed:ty),*) => {
+        $(
+            impl Integer for $unsigned {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$unsigned>::checked_mul(self, other)
+                }
+
+                fn magnitude(self) -> Self {
+                    self
+                }
+            }
+        )*
+        $(
+            impl Integer for $signed {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$signed>::checked_mul(self, other)
+                }
+
+                fn magnitude(self) -> Self {
+                    self.abs()
+                }
+            }
+
+            impl SignedInteger for $signed {
+                fn rem_euclid(self, modulus: Self) -> Self {
+                    <$signed>::rem_euclid(self, modulus)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize; i8, i16, i32, i64, i128, isize);
+
+/// Greatest common divisor, never negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.magnitude(), b.magnitude());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, never negative. Panics on overflow in debug builds
+/// like any arithmetic, but only if the result itself does not fit.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    // dividing first keeps the intermediate product no larger than the result
+    (a / gcd(a, b) * b).magnitude()
+}
+
+/// Least common multiple, or `None` if it does not fit in `T`.
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)).magnitude().checked_mul(b.magnitude())
+}
+
+/// Extended Euclid: `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`.
+pub fn extended_gcd<T: SignedInteger>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `(a * b) % modulus` for `a` and `b` in `0..modulus`, without overflowing
+/// even when the product does not fit.
+pub fn mul_mod<T: Integer>(a: T, b: T, modulus: T) -> T {
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+    // double and add, keeping every partial sum below the modulus
+    let two = T::ONE + T::ONE;
+    let (mut a, mut b, mut result) = (a, b, T::ZERO);
+    while b > T::ZERO {
+        if b % two == T::ONE {
+            result = add_mod(result, a, modulus);
+        }
+        a = add_mod(a, a, modulus);
+        b = b / two;
+    }
+    result
+}
+
+// `(a + b) % modulus` for `a` and `b` in `0..modulus`
+fn add_mod<T: Integer>(a: T, b: T, modulus: T) -> T {
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
+
+/// `base.pow(exponent) % modulus` by repeated squaring. Panics if `modulus`
+/// is not positive.
+pub fn mod_pow<T: Integer>(base: T, exponent: T, modulus: T) -> T {
+    assert!(modulus > T::ZERO, "modulus {:?} is not positive", modulus);
+    let two = T::ONE + T::ONE;
+    let (mut base, mut exponent) = (base % modulus, exponent);
+    if base < T::ZERO {
+        base = base + modulus;
+    }
+    let mut result = T::ONE % modulus;
+    while exponent > T::ZERO {
+        if exponent % two == T::ONE {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent = exponent / two;
+    }
+    result
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, or `None` if `a`
+/// and `modulus` share a factor.
+pub fn mod_inverse<T: SignedInteger>(a: T, modulus: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == T::ONE).then(|| x.rem_euclid(modulus))
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    /// Congruence `index` (0-based) contradicts the ones before it.
+    #[error("congruence {index} contradicts the ones before it")]
+    Inconsistent { index: usize },
+    #[error("the combined modulus does not fit")]
+    Overflow,
+}
+
+/// Solves the system `x ≡ residue (mod modulus)` for every pair, returning
+/// `(x, m)` such that the solutions are exactly `x + k * m`, with `x` in
+/// `0..m`. Moduli need not be coprime. An empty system gives `(0, 1)`.
+/// Panics if a modulus is not positive.
+pub fn crt<T: SignedInteger>(congruences: &[(T, T)]) -> Result<(T, T), CrtError> {
+    let (mut x, mut m) = (T::ZERO, T::ONE);
+    for (index, &(residue, modulus)) in congruences.iter().enumerate() {
+        assert!(modulus > T::ZERO, "modulus {:?} is not positive", modulus);
+        let residue = residue.rem_euclid(modulus);
+        let g = gcd(m, modulus);
+        let difference = (residue - x).rem_euclid(modulus);
+        if difference % g != T::ZERO {
+            return Err(CrtError::Inconsistent { index });
+        }
+        // x + m * t ≡ residue (mod modulus), solved for t modulo modulus / g
+        let step = modulus / g;
+        let inverse = mod_inverse(m / g, step).expect("m / g and modulus / g are coprime");
+        let t = mul_mod((difference / g).rem_euclid(step), inverse, step);
+        let combined = checked_lcm(m, modulus).ok_or(CrtError::Overflow)?;
+        // m * t + x < m * step = combined, so neither overflows
+        x = m * t + x;
+        m = combined;
+    }
+    Ok((x, m))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(4, 6), 2);
+        assert_eq!(gcd(-4_i32, 6), 2);
+        assert_eq!(gcd(0_u8, 7), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4_i64, 6), 12);
+        assert_eq!(lcm(0_u32, 6), 0);
+        // 2^63 * 3 overflows as a product but their lcm fits
+        assert_eq!(lcm(1_u64 << 63, 1 << 62), 1 << 63);
+        assert_eq!(checked_lcm(1_u64 << 63, 3), None);
+        assert_eq!(checked_lcm(255_u8, 5), Some(255));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240_i64, 46), (-7, 3), (0, 5), (17, -17)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(mod_pow(4_u32, 13, 497), 445);
+        assert_eq!(mod_pow(-2_i32, 3, 5), 2);
+        assert_eq!(mod_pow(7_u8, 0, 1), 0);
+        // the squares would overflow u64
+        let p = 18_446_744_073_709_551_557_u64;
+        assert_eq!(mod_pow(2, p - 1, p), 1);
+        assert_eq!(mul_mod(p - 1, p - 1, p), 1);
+
+        assert_eq!(mod_inverse(3_i32, 11), Some(4));
+        assert_eq!(mod_inverse(-3_i32, 11), Some(7));
+        assert_eq!(mod_inverse(6_i32, 9), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2_i64, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+        // moduli sharing a factor
+        assert_eq!(crt(&[(3_i64, 4), (5, 6)]), Ok((11, 12)));
+        assert_eq!(crt(&[(-1_i32, 4)]), Ok((3, 4)));
+        assert_eq!(crt::<i32>(&[]), Ok((0, 1)));
+        assert_eq!(
+            crt(&[(1_i64, 4), (3, 5), (2, 6)]),
+            Err(CrtError::Inconsistent { index: 2 })
+        );
+        assert_eq!(crt(&[(1_i8, 11), (2, 13)]), Err(CrtError::Overflow));
+    }
+}
@@ -0,0 +1,111 @@
+//! Independent work spread over rayon's thread pool when the `parallel`
+//! feature is on, and done in order on the calling thread otherwise. Both
+//! paths give the same results in the same order, which [`sequential`] lets
+//! tests check with the feature on.
+
+use std::cell::Cell;
+
+use crate::Solution;
+
+thread_local! {
+    static SEQUENTIAL: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether [`map`] spreads its work over threads here.
+pub fn enabled() -> bool {
+    cfg!(feature = "parallel") && !SEQUENTIAL.get()
+}
+
+/// Runs `f` with every [`map`] it makes on this thread done in order, as if
+/// the `parallel` feature were off.
+///
+/// The setting belongs to the thread. A sequential [`map`] never leaves it,
+/// so maps nested in one are sequential too; but `sequential` called by an
+/// item of a parallel [`map`] only applies to that item, on whichever rayon
+/// thread runs it.
+pub fn sequential<R>(f: impl FnOnce() -> R) -> R {
+    // puts the outer setting back even if `f` panics
+    struct Restore(bool);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SEQUENTIAL.set(self.0);
+        }
+    }
+
+    let _restore = Restore(SEQUENTIAL.replace(true));
+    f()
+}
+
+/// `f` applied to every item, in the order of `items`.
+pub fn map<T, R>(items: impl IntoIterator<Item = T>, f: impl Fn(T) -> R + Sync + Send) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+{
+    #[cfg(feature = "parallel")]
+    if enabled() {
+        use rayon::prelude::*;
+
+        let items: Vec<T> = items.into_iter().collect();
+        return items.into_par_iter().map(f).collect();
+    }
+    items.into_iter().map(f).collect()
+}
+
+/// Checks that every part of `S` answers each of `inputs` the same with its
+/// work spread over threads as done in order, for a day's tests to call with
+/// a few generated inputs. Without the `parallel` feature both runs would be
+/// in order, so it panics unless the day turns it on for its tests.
+pub fn assert_matches_sequential<S: Solution>(inputs: impl IntoIterator<Item = impl AsRef<str>>) {
+    assert!(
+        enabled(),
+        "day {} tests without the parallel feature",
+        S::DAY
+    );
+    for input in inputs {
+        let input = S::parse(input.as_ref()).expect("the input parses");
+        for &part in S::PARTS {
+            let answer = S::solve(&input, part);
+            assert_eq!(
+                answer,
+                sequential(|| S::solve(&input, part)),
+                "day {} part {}",
+                S::DAY,
+                part
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map() {
+        let squares = map(0..1000u64, |n| n * n);
+        assert_eq!(squares, (0..1000u64).map(|n| n * n).collect::<Vec<_>>());
+        assert_eq!(sequential(|| map(0..1000u64, |n| n * n)), squares);
+        assert!(map(Vec::<u64>::new(), |n| n).is_empty());
+    }
+
+    #[test]
+    fn test_sequential() {
+        assert_eq!(enabled(), cfg!(feature = "parallel"));
+        sequential(|| {
+            assert!(!enabled());
+            sequential(|| assert!(!enabled()));
+            assert!(!enabled());
+        });
+        assert_eq!(enabled(), cfg!(feature = "parallel"));
+
+        let panicked = std::panic::catch_unwind(|| sequential(|| panic!("in sequential")));
+        assert!(panicked.is_err());
+        assert_eq!(enabled(), cfg!(feature = "parallel"));
+
+        // nested maps under `sequential` stay on this thread
+        let thread = std::thread::current().id();
+        let threads = sequential(|| map(0..4, |_| map(0..4, |_| std::thread::current().id())));
+        assert!(threads.iter().flatten().all(|&id| id == thread));
+    }
+}
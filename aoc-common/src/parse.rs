@@ -0,0 +1,69 @@
+//! Small helpers for hand-written parsers, failing with an [`Error::at`]
+//! that points at the offending text instead of panicking.
+//!
+//! Each takes the `input` being parsed and a `text` slice of it, so the
+//! error can say where in `input` the problem is.
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{Error, Result};
+
+/// Parses `text` as a number, or any other `FromStr` type.
+pub fn number<T>(input: &str, text: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.parse()
+        .map_err(|err| Error::at(input, text, format!("invalid number: {}", err)))
+}
+
+/// `text` without `prefix`, which it must start with.
+pub fn strip_prefix<'a>(input: &str, text: &'a str, prefix: &str) -> Result<&'a str> {
+    text.strip_prefix(prefix).ok_or_else(|| {
+        let end = text
+            .char_indices()
+            .nth(prefix.chars().count())
+            .map_or(text.len(), |(index, _)| index);
+        Error::at(input, &text[..end], format!("expected {:?}", prefix))
+    })
+}
+
+/// `text` split around the first `separator`, which it must contain.
+pub fn split_once<'a>(input: &str, text: &'a str, separator: &str) -> Result<(&'a str, &'a str)> {
+    text.split_once(separator)
+        .ok_or_else(|| Error::at(input, text, format!("expected {:?}", separator)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(err: Error) -> (usize, usize, String) {
+        match err {
+            Error::Parse(err) => (err.line, err.column, err.span().to_string()),
+            err => panic!("no position in {}", err),
+        }
+    }
+
+    #[test]
+    fn test_helpers() {
+        let input = "x=12, y=-4\nx=1O, y=3";
+        let line = input.lines().nth(1).unwrap();
+        let (x, y) = split_once(input, line, ", ").unwrap();
+        assert_eq!(strip_prefix(input, y, "y=").unwrap(), "3");
+        assert_eq!(
+            position(number::<i32>(input, strip_prefix(input, x, "x=").unwrap()).unwrap_err()),
+            (2, 3, "1O".to_string())
+        );
+        assert_eq!(
+            position(strip_prefix(input, y, "z=").unwrap_err()),
+            (2, 7, "y=".to_string())
+        );
+        assert_eq!(
+            position(split_once(input, line, ";").unwrap_err()),
+            (2, 1, line.to_string())
+        );
+        assert_eq!(number::<i32>(input, "-4").unwrap(), -4);
+    }
+}
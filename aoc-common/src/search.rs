@@ -0,0 +1,256 @@
+//! Shortest paths over any graph given as a `neighbours` function, e.g. the
+//! cells a step away on a grid.
+//!
+//! Every search starts from any number of nodes at once, which finds the
+//! nearest of several starts in one pass, and stops at the first node that
+//! `is_goal` accepts; pass `|_| false` to explore everything reachable.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// What a search found: the cheapest known cost of every node it reached and
+/// where each was reached from. When a search stops at a goal, costs of nodes
+/// no closer than the goal may be upper bounds only.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    costs: HashMap<N, C>,
+    parents: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> Search<N, C> {
+    /// The goal the search stopped at, if it reached one.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// The cost of the cheapest path to the goal.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    /// The cost of the cheapest known path to `node`, if the search reached
+    /// it.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// Every node reached with the cost of getting there.
+    pub fn costs(&self) -> impl Iterator<Item = (&N, C)> {
+        self.costs.iter().map(|(node, &cost)| (node, cost))
+    }
+
+    /// The cheapest path to the goal, from its start to the goal itself.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    /// The cheapest path to `node`, from its start to `node` itself.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.costs.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last()?) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search where every step costs one.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search {
+        costs: HashMap::new(),
+        parents: HashMap::new(),
+        goal: None,
+    };
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.costs.contains_key(&start) {
+            search.costs.insert(start.clone(), 0);
+            queue.push_back((start, 0));
+        }
+    }
+    while let Some((node, cost)) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        for next in neighbours(&node) {
+            if !search.costs.contains_key(&next) {
+                search.costs.insert(next.clone(), cost + 1);
+                search.parents.insert(next.clone(), node.clone());
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm, for steps of varying cost. `C::default()` must be
+/// zero and costs must not be negative.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// A*: Dijkstra's algorithm guided towards the goal by `heuristic`, an
+/// estimate of the cost left from a node. The path found is the cheapest as
+/// long as the estimate never exceeds the real cost.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search {
+        costs: HashMap::new(),
+        parents: HashMap::new(),
+        goal: None,
+    };
+    // the heap holds indices into `nodes` so nodes need not be `Ord`
+    let mut nodes = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if !search.costs.contains_key(&start) {
+            search.costs.insert(start.clone(), C::default());
+            heap.push(Reverse((heuristic(&start), C::default(), nodes.len())));
+            nodes.push(start);
+        }
+    }
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        // a cheaper way to this node was queued after this one
+        if search.costs[&node] < cost {
+            continue;
+        }
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if search
+                .costs
+                .get(&next)
+                .is_some_and(|&known| known <= next_cost)
+            {
+                continue;
+            }
+            search.costs.insert(next.clone(), next_cost);
+            search.parents.insert(next.clone(), node.clone());
+            heap.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                nodes.len(),
+            )));
+            nodes.push(next);
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{Direction, Point};
+
+    // a 5x5 room with a wall down column 2 from row 0 to row 3
+    fn open(point: Point<i32>) -> bool {
+        (0..5).contains(&point.x) && (0..5).contains(&point.y) && !(point.x == 2 && point.y < 4)
+    }
+
+    fn steps(point: &Point<i32>) -> Vec<Point<i32>> {
+        Direction::ALL
+            .into_iter()
+            .map(|direction| *point + direction.step())
+            .filter(|&next| open(next))
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let end = Point::new(4, 0);
+        let search = bfs([Point::new(0, 0)], steps, |&point| point == end);
+        assert_eq!(search.goal(), Some(&end));
+        assert_eq!(search.goal_cost(), Some(12));
+        let path = search.path().unwrap();
+        assert_eq!(path.len(), 13);
+        assert_eq!(path[0], Point::new(0, 0));
+        assert!(path.contains(&Point::new(2, 4)));
+        assert!(path.windows(2).all(|step| step[0].manhattan(step[1]) == 1));
+
+        // exploring everything reaches the whole room but the wall
+        let search = bfs([Point::new(0, 0)], steps, |_| false);
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.costs().count(), 21);
+        assert_eq!(search.path_to(&Point::new(2, 0)), None);
+    }
+
+    #[test]
+    fn test_multi_source() {
+        let starts = [Point::new(0, 0), Point::new(3, 3)];
+        let search = bfs(starts, steps, |&point| point == Point::new(4, 0));
+        assert_eq!(search.goal_cost(), Some(4));
+        assert_eq!(search.path().unwrap()[0], Point::new(3, 3));
+        assert_eq!(search.cost(&Point::new(0, 1)), Some(1));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // the direct edge is dearer than going around
+        let edges: HashMap<char, Vec<(char, u32)>> = HashMap::from([
+            ('a', vec![('b', 7), ('c', 2)]),
+            ('c', vec![('d', 1), ('b', 6)]),
+            ('d', vec![('b', 1)]),
+            ('b', vec![('e', 1)]),
+        ]);
+        let neighbours = |node: &char| edges.get(node).cloned().unwrap_or_default();
+        let search = dijkstra(['a'], neighbours, |&node| node == 'e');
+        assert_eq!(search.goal_cost(), Some(5));
+        assert_eq!(search.path().unwrap(), ['a', 'c', 'd', 'b', 'e']);
+
+        let search = dijkstra(['a'], neighbours, |&node| node == 'z');
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.cost(&'b'), Some(4));
+    }
+
+    #[test]
+    fn test_astar() {
+        let end = Point::new(4, 0);
+        let neighbours = |point: &Point<i32>| steps(point).into_iter().map(|next| (next, 1));
+        let search = astar(
+            [Point::new(0, 0)],
+            neighbours,
+            |point| point.manhattan(end),
+            |&point| point == end,
+        );
+        assert_eq!(search.goal_cost(), Some(12));
+        assert_eq!(search.path().unwrap().len(), 13);
+    }
+}
@@ -0,0 +1,206 @@
+//! A driver for the tick-based puzzles: the rope of day9, the CPU of day10,
+//! the monkeys of day11 and the sand of day14.
+//!
+//! A puzzle only says how its [`State`] advances by one tick; [`Simulation`]
+//! runs it for a number of ticks or until a condition holds, calls observers
+//! after every tick, and keeps snapshots to rewind to an earlier tick.
+
+/// Something that advances one tick at a time.
+pub trait State: Clone {
+    /// Advances by one tick, or returns `false` without changing anything
+    /// once there is nothing left to do.
+    fn step(&mut self) -> bool;
+}
+
+type Observer<'a, S> = Box<dyn FnMut(usize, &S) + 'a>;
+
+pub struct Simulation<'a, S: State> {
+    state: S,
+    tick: usize,
+    finished: bool,
+    // (tick, state), the first one being the initial state
+    snapshots: Vec<(usize, S)>,
+    snapshot_every: usize,
+    observers: Vec<Observer<'a, S>>,
+}
+
+impl<'a, S: State> Simulation<'a, S> {
+    /// A simulation starting from `state` at tick 0. Only the initial state
+    /// is kept as a snapshot until [`Simulation::snapshot_every`] says more.
+    pub fn new(state: S) -> Self {
+        Simulation {
+            snapshots: vec![(0, state.clone())],
+            state,
+            tick: 0,
+            finished: false,
+            snapshot_every: 0,
+            observers: Vec::new(),
+        }
+    }
+
+    /// Keeps a copy of the state every `ticks` ticks, so rewinding only has
+    /// to replay the ticks since the nearest one. 0 keeps none.
+    pub fn snapshot_every(mut self, ticks: usize) -> Self {
+        self.snapshot_every = ticks;
+        self
+    }
+
+    /// Calls `observer` with the tick number and the new state after every
+    /// tick, e.g. to collect the positions a rope's tail visits.
+    pub fn observe(mut self, observer: impl FnMut(usize, &S) + 'a) -> Self {
+        self.observers.push(Box::new(observer));
+        self
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn into_state(self) -> S {
+        self.state
+    }
+
+    /// How many ticks have run.
+    pub fn tick(&self) -> usize {
+        self.tick
+    }
+
+    /// Whether the state had nothing left to do on the last step.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Runs one tick, returning `false` if the simulation is finished.
+    pub fn step(&mut self) -> bool {
+        if self.finished || !self.state.step() {
+            self.finished = true;
+            return false;
+        }
+        self.tick += 1;
+        if self.snapshot_every > 0 && self.tick.is_multiple_of(self.snapshot_every) {
+            self.snapshots.push((self.tick, self.state.clone()));
+        }
+        for observer in &mut self.observers {
+            observer(self.tick, &self.state);
+        }
+        true
+    }
+
+    /// Runs up to `ticks` ticks, fewer if the simulation finishes, and
+    /// returns how many ran.
+    pub fn run_for(&mut self, ticks: usize) -> usize {
+        (0..ticks).take_while(|_| self.step()).count()
+    }
+
+    /// Runs until `predicate` holds for the state, checking the current state
+    /// first. Returns `false` if the simulation finished before it did.
+    pub fn run_until(&mut self, mut predicate: impl FnMut(&S) -> bool) -> bool {
+        while !predicate(&self.state) {
+            if !self.step() {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Runs until the simulation finishes, returning how many ticks ran.
+    pub fn run(&mut self) -> usize {
+        let start = self.tick;
+        while self.step() {}
+        self.tick - start
+    }
+
+    /// Goes back to the state after `tick` ticks by restoring the nearest
+    /// snapshot and replaying from it, without calling the observers again.
+    /// Panics if `tick` has not been reached yet.
+    pub fn rewind(&mut self, tick: usize) {
+        assert!(
+            tick <= self.tick,
+            "cannot rewind to tick {} from tick {}",
+            tick,
+            self.tick
+        );
+        self.snapshots.retain(|&(taken, _)| taken <= tick);
+        let (taken, snapshot) = self.snapshots.last().expect("the initial state is kept");
+        self.state = snapshot.clone();
+        for _ in *taken..tick {
+            self.state.step();
+        }
+        self.tick = tick;
+        self.finished = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // counts down to zero, remembering every value it had
+    #[derive(Debug, Clone, PartialEq)]
+    struct Countdown(Vec<u32>);
+
+    impl State for Countdown {
+        fn step(&mut self) -> bool {
+            match self.0.last() {
+                Some(&n) if n > 0 => {
+                    self.0.push(n - 1);
+                    true
+                }
+                _ => false,
+            }
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let mut simulation = Simulation::new(Countdown(vec![5]));
+        assert_eq!(simulation.run_for(2), 2);
+        assert_eq!(simulation.state().0, [5, 4, 3]);
+        assert!(simulation.run_until(|countdown| countdown.0.ends_with(&[1])));
+        assert_eq!(simulation.tick(), 4);
+        assert!(!simulation.is_finished());
+        assert_eq!(simulation.run(), 1);
+        assert!(simulation.is_finished());
+        assert_eq!(simulation.run_for(3), 0);
+        assert!(!simulation.run_until(|countdown| countdown.0.len() > 10));
+        assert_eq!(simulation.into_state().0, [5, 4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_observe() {
+        let mut seen = Vec::new();
+        let mut simulation = Simulation::new(Countdown(vec![3]))
+            .observe(|tick, countdown: &Countdown| seen.push((tick, *countdown.0.last().unwrap())));
+        simulation.run();
+        drop(simulation);
+        assert_eq!(seen, [(1, 2), (2, 1), (3, 0)]);
+    }
+
+    #[test]
+    fn test_rewind() {
+        let mut steps = 0;
+        let mut simulation = Simulation::new(Countdown(vec![10]))
+            .snapshot_every(4)
+            .observe(|_, _| steps += 1);
+        simulation.run();
+        simulation.rewind(6);
+        assert_eq!(simulation.tick(), 6);
+        assert_eq!(simulation.state().0.last(), Some(&4));
+        assert!(!simulation.is_finished());
+        simulation.rewind(2);
+        assert_eq!(simulation.state().0, [10, 9, 8]);
+        simulation.rewind(0);
+        assert_eq!(simulation.state().0, [10]);
+        assert_eq!(simulation.run(), 10);
+        drop(simulation);
+        assert_eq!(steps, 20);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot rewind to tick 3 from tick 1")]
+    fn test_rewind_to_the_future() {
+        let mut simulation = Simulation::new(Countdown(vec![10]));
+        simulation.step();
+        simulation.rewind(3);
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt;
+
+use crate::{Answer, Error, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = Error;
+
+    fn try_from(part: u8) -> Result<Self> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(Error::InvalidInput(format!("part {} does not exist", part))),
+        }
+    }
+}
+
+/// The event the days in this workspace were written for, unless they say
+/// otherwise with [`Solution::YEAR`].
+pub const DEFAULT_YEAR: u16 = 2022;
+
+/// A day's puzzle: parse the input once, then answer each part from the parsed
+/// value. Parsing and solving are separate so the runner can time them apart.
+pub trait Solution {
+    const DAY: u8;
+
+    /// The event the puzzle is from, so days of several years can share the
+    /// runner.
+    const YEAR: u16 = DEFAULT_YEAR;
+
+    /// Parts with a solver. The runner reports the others as not implemented
+    /// without calling them.
+    const PARTS: &'static [Part] = &Part::ALL;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parses the puzzle's worked example, which some days ask a different
+    /// question of than the real input, e.g. another row in day15.
+    fn parse_example(input: &str) -> Result<Self::Input> {
+        Self::parse(input)
+    }
+
+    fn part_one(_input: &Self::Input) -> Answer {
+        Answer::Unimplemented
+    }
+
+    fn part_two(_input: &Self::Input) -> Answer {
+        Answer::Unimplemented
+    }
+
+    /// More than the answers, for `aoc run --breakdown`, e.g. how day2 scores
+    /// every round. `None` for days with nothing more to show.
+    fn breakdown(_input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    fn solve(input: &Self::Input, part: Part) -> Answer {
+        if !Self::PARTS.contains(&part) {
+            return Answer::Unimplemented;
+        }
+        match part {
+            Part::One => Self::part_one(input),
+            Part::Two => Self::part_two(input),
+        }
+    }
+}
+
+/// The solver of part `PART` of a day, registered by putting
+/// `#[aoc(day = N, part = PART)]` from `aoc-macros` on a function. An
+/// `#[aoc]` on the day's `impl Solution` answers each of its `PARTS` with it,
+/// or implements it with the part's method if that is written out instead.
+#[diagnostic::on_unimplemented(
+    message = "no solver is registered for part {PART} of `{Self}`",
+    label = "`{Self}` lists part {PART} in its `PARTS`",
+    note = "register one with `#[aoc(day = .., part = {PART})]` on a function, or drop the part from `PARTS`"
+)]
+pub trait SolvePart<const PART: u8>: Solution {
+    fn solve_part(input: &Self::Input) -> Answer;
+}
+
+/// A solution the runner may list, which `#[aoc(day = N)]` from `aoc-macros`
+/// on its `impl Solution` makes it.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not registered with the runner",
+    label = "the runner lists every day crate it depends on",
+    note = "put `#[aoc(day = ..)]` from aoc-macros on its `impl Solution`"
+)]
+pub trait Registered: Solution {}
@@ -0,0 +1,346 @@
+//! A rectangular 2D grid for the puzzles drawn as character maps: day8's
+//! trees, day12's heightmap and day14's cave.
+//!
+//! Positions are [`Point`]s with `x` the column and `y` the row, `(0, 0)`
+//! being the top-left cell as printed in the puzzle.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use aoc_common::{
+    geometry::{Direction, Point},
+    Error, Result,
+};
+
+/// A cell's position: column, then row.
+pub type Pos = Point<usize>;
+
+/// Steps to all 8 neighbours, clockwise from up.
+pub const ALL_DIRECTIONS: [Point<isize>; 8] = [
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+    Point::new(0, 1),
+    Point::new(-1, 1),
+    Point::new(-1, 0),
+    Point::new(-1, -1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    // row after row
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` x `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(Error::InvalidInput(format!(
+                    "row {} has {} cells, expected {}",
+                    y + 1,
+                    row.len(),
+                    width
+                )));
+            }
+            cells.extend(row);
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parses a character map, one row per line, turning each character into
+    /// a cell with `cell`. A character `cell` maps to `None` is an error.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for line in input.lines() {
+            let row = line
+                .char_indices()
+                .map(|(index, c)| {
+                    cell(c).ok_or_else(|| {
+                        let span = &line[index..index + c.len_utf8()];
+                        Error::at(input, span, format!("unexpected {:?}", c))
+                    })
+                })
+                .collect::<Result<Vec<T>>>()?;
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(Error::at(
+                        input,
+                        line,
+                        format!("row has {} cells, expected {}", row.len(), first.len()),
+                    ));
+                }
+            }
+            rows.push(row);
+        }
+        Grid::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|index| &mut self.cells[index])
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        self.contains(pos).then(|| pos.y * self.width + pos.x)
+    }
+
+    /// The position one `step` away from `pos`, if it is still in the grid.
+    pub fn offset(&self, pos: Pos, step: Point<isize>) -> Option<Pos> {
+        let pos = (pos.try_cast::<isize>()? + step).try_cast()?;
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The first position, row by row, whose cell matches.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` keeps `chunks` happy on a grid with no columns
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Row `y`, left to right. Panics if `y` is out of bounds.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(
+            y < self.height,
+            "row {} out of a {} row grid",
+            y,
+            self.height
+        );
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Column `x`, top to bottom. Panics if `x` is out of bounds.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {} out of a {} column grid",
+            x,
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// The positions met walking from `pos` (excluded) by `step` until the
+    /// edge of the grid.
+    pub fn ray(&self, pos: Pos, step: Point<isize>) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.offset(pos, step), move |&pos| self.offset(pos, step))
+    }
+
+    /// The up to 4 orthogonal neighbours of `pos` inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.offset(pos, direction.step()))
+    }
+
+    /// The up to 8 neighbours of `pos`, diagonals included, inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ALL_DIRECTIONS
+            .into_iter()
+            .filter_map(move |step| self.offset(pos, step))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Draws the grid back as a character map, the inverse of [`Grid::parse`].
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut cell).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.index_of(pos) {
+            Some(index) => &self.cells[index],
+            None => panic!("{} out of a {}x{} grid", pos, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        match self.index_of(pos) {
+            Some(index) => &mut self.cells[index],
+            None => panic!("{} out of a {}x{} grid", pos, self.width, self.height),
+        }
+    }
+}
+
+/// Prints each cell with its own `Display`, one row per line.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(x: usize, y: usize) -> Pos {
+        Point::new(x, y)
+    }
+
+    fn digits(input: &str) -> Grid<u8> {
+        Grid::parse(input, |c| c.to_digit(10).map(|d| d as u8)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[p(0, 0)], 1);
+        assert_eq!(grid[p(2, 0)], 3);
+        assert_eq!(grid[p(0, 1)], 4);
+        assert_eq!(grid.get(p(3, 0)), None);
+        assert_eq!(grid.get(p(0, 2)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+        assert_eq!(
+            grid.render(|&d| if d % 2 == 0 { '#' } else { '.' }),
+            ".#.\n#.#"
+        );
+
+        let err = Grid::parse("12\n3x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 2: unexpected 'x'");
+        let err = Grid::parse("12\n3", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: row has 1 cells, expected 2"
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits("123\n456");
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(2).collect::<Vec<_>>(), [&3, &6]);
+        assert_eq!(grid.position(|&d| d > 4), Some(p(1, 1)));
+        assert_eq!(
+            grid.iter().filter(|(_, &d)| d % 2 == 0).collect::<Vec<_>>(),
+            [(p(1, 0), &2), (p(0, 1), &4), (p(2, 1), &6)]
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(4, 3, '.');
+        assert_eq!(
+            grid.neighbours4(p(0, 0)).collect::<Vec<_>>(),
+            [p(1, 0), p(0, 1)]
+        );
+        assert_eq!(grid.neighbours4(p(1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbours8(p(3, 2)).collect::<Vec<_>>(),
+            [p(3, 1), p(2, 2), p(2, 1)]
+        );
+        assert_eq!(grid.neighbours8(p(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::new(4, 3, 0);
+        assert_eq!(
+            grid.ray(p(1, 1), Point::new(1, 0)).collect::<Vec<_>>(),
+            [p(2, 1), p(3, 1)]
+        );
+        assert_eq!(
+            grid.ray(p(1, 1), Point::new(-1, -1)).collect::<Vec<_>>(),
+            [p(0, 0)]
+        );
+        assert_eq!(grid.ray(p(0, 2), Point::new(0, 1)).count(), 0);
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut grid = Grid::new(2, 2, false);
+        grid[p(1, 0)] = true;
+        *grid.get_mut(p(0, 1)).unwrap() = true;
+        assert!(grid.get_mut(p(2, 0)).is_none());
+        assert_eq!(
+            grid.map(|&rock| if rock { '#' } else { '.' }).to_string(),
+            ".#\n#."
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "(2, 0) out of a 2x2 grid")]
+    fn test_index_out_of_bounds() {
+        let grid = Grid::new(2, 2, 0);
+        let _ = grid[p(2, 0)];
+    }
+}
@@ -0,0 +1,367 @@
+//! Attributes that register a day's solvers with the runner and turn the
+//! puzzle's examples into tests:
+//!
+//! ```ignore
+//! #[aoc(day = 12)]
+//! impl Solution for Day12 {
+//!     type Input = Heightmap;
+//!
+//!     fn parse(input: &str) -> Result<Self::Input> { ... }
+//! }
+//!
+//! #[aoc(day = 12, part = 2)]
+//! #[aoc_example(expected = 29)]
+//! fn fewest_steps_from_any_a(heightmap: &Heightmap) -> Option<usize> { ... }
+//! ```
+//!
+//! On a function, `#[aoc]` implements [`SolvePart`] for the day, so two
+//! solvers of the same part do not compile. On the `impl Solution`, it sets
+//! `DAY`, answers every part in `PARTS` with its registered solver, so a part
+//! without one does not compile either, and implements [`Registered`], which
+//! the runner's table of days asks of every day crate it depends on. A part
+//! whose method is written out by hand is its own solver.
+//!
+//! [`SolvePart`]: https://docs.rs/aoc-common/latest/aoc_common/trait.SolvePart.html
+//! [`Registered`]: https://docs.rs/aoc-common/latest/aoc_common/trait.Registered.html
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::Parser, punctuated::Punctuated, spanned::Spanned, Attribute, Error, Expr, ExprArray,
+    ExprLit, ImplItem, Item, ItemFn, ItemImpl, Lit, LitInt, LitStr, MetaNameValue, Result, Token,
+};
+
+/// `#[aoc(day = N, part = P)]` on a function registers it as the solver of
+/// that part, and `#[aoc(day = N)]` on the day's `impl Solution` wires the
+/// registered solvers into it. See the crate docs.
+#[proc_macro_attribute]
+pub fn aoc(args: TokenStream, item: TokenStream) -> TokenStream {
+    let expanded = Args::parse(args.into()).and_then(|args| match syn::parse::<Item>(item)? {
+        Item::Fn(solver) => register_solver(&args, solver),
+        Item::Impl(solution) => wire_solution(&args, solution),
+        other => Err(Error::new(
+            other.span(),
+            "#[aoc] goes on a solver function or on the day's `impl Solution`",
+        )),
+    });
+    expanded.unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// `#[aoc_example(expected = ..)]` below the `#[aoc(day, part)]` of a solver
+/// tests it on the day's example in `inputs/examples/`; `input = ".."` gives
+/// the example inline instead and `file = ".."` reads it from a file, relative
+/// to the source file like `include_str!`. Repeat it for several examples.
+/// Each is parsed with the day's `parse_example`.
+#[proc_macro_attribute]
+pub fn aoc_example(args: TokenStream, item: TokenStream) -> TokenStream {
+    // `#[aoc]` above has made the tests already, and marked what it left here
+    let item = TokenStream2::from(item);
+    if syn::parse::<syn::Ident>(args).is_ok_and(|marker| marker == REGISTERED) {
+        return item.into();
+    }
+    let error = Error::new(
+        Span::call_site(),
+        "#[aoc_example] goes below the #[aoc(day = .., part = ..)] of the solver it tests",
+    )
+    .into_compile_error();
+    quote!(#error #item).into()
+}
+
+// What `#[aoc]` leaves of the examples it has turned into tests. They stay on
+// the solver so that importing `aoc_example` is not reported as unused.
+const REGISTERED: &str = "__registered_by_aoc";
+
+#[derive(Default)]
+struct Args {
+    day: Option<(u8, Span)>,
+    part: Option<(u8, Span)>,
+}
+
+impl Args {
+    fn parse(tokens: TokenStream2) -> Result<Self> {
+        let mut args = Args::default();
+        for arg in Punctuated::<MetaNameValue, Token![,]>::parse_terminated.parse2(tokens)? {
+            let value = int(&arg.value)?;
+            let slot = if arg.path.is_ident("day") {
+                if !(1..=25).contains(&value) {
+                    return Err(Error::new(arg.value.span(), "days go from 1 to 25"));
+                }
+                &mut args.day
+            } else if arg.path.is_ident("part") {
+                if !(1..=2).contains(&value) {
+                    return Err(Error::new(arg.value.span(), "parts are 1 or 2"));
+                }
+                &mut args.part
+            } else {
+                return Err(Error::new(arg.path.span(), "expected `day` or `part`"));
+            };
+            if slot.is_some() {
+                return Err(Error::new(arg.path.span(), "given twice"));
+            }
+            *slot = Some((value, arg.value.span()));
+        }
+        Ok(args)
+    }
+}
+
+fn int(value: &Expr) -> Result<u8> {
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse(),
+        _ => Err(Error::new(value.span(), "expected a number")),
+    }
+}
+
+fn day_type(day: u8) -> syn::Ident {
+    format_ident!("Day{}", day)
+}
+
+fn register_solver(args: &Args, mut solver: ItemFn) -> Result<TokenStream2> {
+    let (Some((day, day_span)), Some((part, _))) = (args.day, args.part) else {
+        return Err(Error::new(
+            Span::call_site(),
+            "a solver is registered with #[aoc(day = .., part = ..)]",
+        ));
+    };
+    if solver.sig.inputs.len() != 1 || !solver.sig.generics.params.is_empty() {
+        return Err(Error::new(
+            solver.sig.span(),
+            "a solver takes the parsed input by reference, and nothing else",
+        ));
+    }
+
+    let is_example = |attr: &Attribute| {
+        attr.path()
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "aoc_example")
+    };
+    let examples: Vec<Attribute> = solver
+        .attrs
+        .iter()
+        .filter(|attr| is_example(attr))
+        .cloned()
+        .collect();
+    let marker = format_ident!("{}", REGISTERED);
+    for attr in solver.attrs.iter_mut().filter(|attr| is_example(attr)) {
+        let path = attr.path().clone();
+        *attr = syn::parse_quote!(#[#path(#marker)]);
+    }
+
+    let name = &solver.sig.ident;
+    let day_type = day_type(day);
+    let mismatch = LitStr::new(
+        &format!("#[aoc(day = {})] on a solver of another day", day),
+        day_span,
+    );
+    let part = LitInt::new(&part.to_string(), Span::call_site());
+    let tests = examples
+        .iter()
+        .enumerate()
+        .map(|(index, example)| example_test(example, index, &day_type, &part, name))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        #solver
+
+        impl ::aoc_common::SolvePart<#part> for crate::#day_type {
+            fn solve_part(
+                input: &<Self as ::aoc_common::Solution>::Input,
+            ) -> ::aoc_common::Answer {
+                ::aoc_common::Answer::from(#name(input))
+            }
+        }
+
+        const _: () = ::core::assert!(
+            <crate::#day_type as ::aoc_common::Solution>::DAY == #day,
+            #mismatch
+        );
+
+        #(#tests)*
+    })
+}
+
+fn example_test(
+    example: &Attribute,
+    index: usize,
+    day_type: &syn::Ident,
+    part: &LitInt,
+    solver: &syn::Ident,
+) -> Result<TokenStream2> {
+    let mut input = None;
+    let mut expected = None;
+    let args = example.parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)?;
+    for arg in args {
+        let text = || match &arg.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(text),
+                ..
+            }) => Ok(text.clone()),
+            value => Err(Error::new(value.span(), "expected a string")),
+        };
+        if arg.path.is_ident("input") {
+            let text = text()?;
+            input = Some(quote!(::std::string::String::from(#text)));
+        } else if arg.path.is_ident("file") {
+            let path = text()?;
+            input = Some(quote!(::std::string::String::from(
+                ::core::include_str!(#path)
+            )));
+        } else if arg.path.is_ident("expected") {
+            expected = Some(arg.value.to_token_stream());
+        } else {
+            return Err(Error::new(
+                arg.path.span(),
+                "expected `input`, `file` or `expected`",
+            ));
+        }
+    }
+    let Some(expected) = expected else {
+        return Err(Error::new(
+            example.span(),
+            "an example needs the answer it should give: #[aoc_example(expected = ..)]",
+        ));
+    };
+    // the day's example fixture, found the way `aoc run --example` finds it
+    let input = input.unwrap_or_else(|| {
+        quote! {
+            ::aoc_common::input::Inputs::from_env()
+                .year(<crate::#day_type as ::aoc_common::Solution>::YEAR)
+                .load(
+                    <crate::#day_type as ::aoc_common::Solution>::DAY,
+                    &::aoc_common::input::InputSource::Example,
+                )
+                .unwrap()
+        }
+    });
+
+    let test = match index {
+        0 => format_ident!("{}_example", solver),
+        _ => format_ident!("{}_example_{}", solver, index + 1),
+    };
+    Ok(quote! {
+        #[cfg(test)]
+        #[test]
+        fn #test() {
+            let input = #input;
+            let parsed = <crate::#day_type as ::aoc_common::Solution>::parse_example(&input).unwrap();
+            ::core::assert_eq!(
+                <crate::#day_type as ::aoc_common::SolvePart<#part>>::solve_part(&parsed),
+                ::aoc_common::Answer::from(#expected)
+            );
+        }
+    })
+}
+
+fn wire_solution(args: &Args, mut solution: ItemImpl) -> Result<TokenStream2> {
+    if let Some((_, span)) = args.part {
+        return Err(Error::new(
+            span,
+            "parts are registered on their solvers, not on `impl Solution`",
+        ));
+    }
+    let is_solution = solution
+        .trait_
+        .as_ref()
+        .and_then(|(_, path, _)| path.segments.last())
+        .is_some_and(|segment| segment.ident == "Solution");
+    if !is_solution {
+        return Err(Error::new(
+            solution.self_ty.span(),
+            "#[aoc] goes on the day's `impl Solution`",
+        ));
+    }
+
+    let mut parts = vec![1, 2];
+    let mut defined = Vec::new();
+    for item in &solution.items {
+        match item {
+            ImplItem::Const(constant) if constant.ident == "PARTS" => {
+                parts = listed_parts(&constant.expr)?;
+            }
+            ImplItem::Const(constant) if constant.ident == "DAY" && args.day.is_some() => {
+                return Err(Error::new(
+                    constant.span(),
+                    "DAY is already set by #[aoc(day = ..)]",
+                ));
+            }
+            ImplItem::Fn(method) => defined.push(method.sig.ident.to_string()),
+            _ => {}
+        }
+    }
+
+    if let Some((day, _)) = args.day {
+        solution
+            .items
+            .insert(0, syn::parse_quote!(const DAY: u8 = #day;));
+    }
+    let self_ty = &solution.self_ty;
+    // parts written out by hand are their own solvers, so that registering
+    // another one for them does not compile either
+    let mut hand_written = Vec::new();
+    for part in parts {
+        let method = match part {
+            1 => format_ident!("part_one"),
+            _ => format_ident!("part_two"),
+        };
+        let part = LitInt::new(&part.to_string(), Span::call_site());
+        if defined.contains(&method.to_string()) {
+            hand_written.push(quote! {
+                impl ::aoc_common::SolvePart<#part> for #self_ty {
+                    fn solve_part(
+                        input: &<Self as ::aoc_common::Solution>::Input,
+                    ) -> ::aoc_common::Answer {
+                        <Self as ::aoc_common::Solution>::#method(input)
+                    }
+                }
+            });
+            continue;
+        }
+        solution.items.push(syn::parse_quote! {
+            fn #method(input: &Self::Input) -> ::aoc_common::Answer {
+                <Self as ::aoc_common::SolvePart<#part>>::solve_part(input)
+            }
+        });
+    }
+    Ok(quote! {
+        #solution
+
+        #(#hand_written)*
+
+        impl ::aoc_common::Registered for #self_ty {}
+    })
+}
+
+// The parts in `PARTS`, e.g. 2 for `&[Part::Two]` and both for `&Part::ALL`
+fn listed_parts(expr: &Expr) -> Result<Vec<u8>> {
+    let error = || {
+        Error::new(
+            expr.span(),
+            "expected a list like `&[Part::One, Part::Two]`",
+        )
+    };
+    let Expr::Reference(reference) = expr else {
+        return Err(error());
+    };
+    let elems = match &*reference.expr {
+        Expr::Array(ExprArray { elems, .. }) => elems,
+        Expr::Path(path) if path.path.segments.last().is_some_and(|s| s.ident == "ALL") => {
+            return Ok(vec![1, 2]);
+        }
+        _ => return Err(error()),
+    };
+    elems
+        .iter()
+        .map(|elem| match elem {
+            Expr::Path(path) if path.path.segments.last().is_some_and(|s| s.ident == "One") => {
+                Ok(1)
+            }
+            Expr::Path(path) if path.path.segments.last().is_some_and(|s| s.ident == "Two") => {
+                Ok(2)
+            }
+            _ => Err(error()),
+        })
+        .collect()
+}
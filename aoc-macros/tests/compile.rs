@@ -0,0 +1,12 @@
+#[test]
+fn test_compile() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/registered.rs");
+    cases.pass("tests/ui/hand_written.rs");
+    cases.compile_fail("tests/ui/duplicate.rs");
+    cases.compile_fail("tests/ui/missing.rs");
+    cases.compile_fail("tests/ui/wrong_day.rs");
+    cases.compile_fail("tests/ui/misplaced_example.rs");
+    cases.compile_fail("tests/ui/hand_written_duplicate.rs");
+    cases.compile_fail("tests/ui/unregistered.rs");
+}
@@ -0,0 +1,30 @@
+use aoc_common::{Result, Solution};
+use aoc_macros::aoc;
+
+pub struct Day1;
+
+#[aoc(day = 1)]
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+
+    fn parse(_input: &str) -> Result<Self::Input> {
+        Ok(Vec::new())
+    }
+}
+
+#[aoc(day = 1, part = 1)]
+fn total(numbers: &Vec<u32>) -> u32 {
+    numbers.iter().sum()
+}
+
+#[aoc(day = 1, part = 1)]
+fn total_again(numbers: &Vec<u32>) -> u32 {
+    numbers.iter().sum()
+}
+
+#[aoc(day = 1, part = 2)]
+fn largest(numbers: &Vec<u32>) -> Option<u32> {
+    numbers.iter().copied().max()
+}
+
+fn main() {}
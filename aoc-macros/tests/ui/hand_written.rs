@@ -0,0 +1,34 @@
+use aoc_common::{Answer, Part, Registered, Result, Solution, SolvePart};
+use aoc_macros::aoc;
+
+pub struct Day1;
+
+#[aoc(day = 1)]
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(|line| line.parse().unwrap()).collect())
+    }
+
+    fn part_one(numbers: &Self::Input) -> Answer {
+        numbers.iter().sum::<u32>().into()
+    }
+}
+
+#[aoc(day = 1, part = 2)]
+fn largest(numbers: &Vec<u32>) -> Option<u32> {
+    numbers.iter().copied().max()
+}
+
+fn registered<S: Registered>() -> u8 {
+    S::DAY
+}
+
+fn main() {
+    let input = Day1::parse("4\n5").unwrap();
+    assert_eq!(registered::<Day1>(), 1);
+    assert_eq!(Day1::solve(&input, Part::One), 9.into());
+    assert_eq!(<Day1 as SolvePart<1>>::solve_part(&input), 9.into());
+    assert_eq!(Day1::solve(&input, Part::Two), 5.into());
+}
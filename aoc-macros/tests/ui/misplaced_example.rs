@@ -0,0 +1,8 @@
+use aoc_macros::aoc_example;
+
+#[aoc_example(input = "1", expected = 1)]
+fn total(numbers: &Vec<u32>) -> u32 {
+    numbers.iter().sum()
+}
+
+fn main() {}
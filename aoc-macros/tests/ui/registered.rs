@@ -0,0 +1,33 @@
+use aoc_common::{Part, Result, Solution, SolvePart};
+use aoc_macros::{aoc, aoc_example};
+
+pub struct Day1;
+
+#[aoc(day = 1)]
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(|line| line.parse().unwrap()).collect())
+    }
+}
+
+#[aoc(day = 1, part = 1)]
+#[aoc_example(input = "1\n2\n3", expected = 6)]
+fn total(numbers: &[u32]) -> u32 {
+    numbers.iter().sum()
+}
+
+#[aoc(day = 1, part = 2)]
+#[aoc_example(input = "1\n2\n3", expected = 3)]
+#[aoc_example(input = "7", expected = 7)]
+fn largest(numbers: &Vec<u32>) -> Option<u32> {
+    numbers.iter().copied().max()
+}
+
+fn main() {
+    let input = Day1::parse("4\n5").unwrap();
+    assert_eq!(Day1::DAY, 1);
+    assert_eq!(Day1::solve(&input, Part::One), 9.into());
+    assert_eq!(<Day1 as SolvePart<2>>::solve_part(&input), 5.into());
+}
@@ -0,0 +1,25 @@
+use aoc_common::{Answer, Registered, Result, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<u32>;
+
+    fn parse(_input: &str) -> Result<Self::Input> {
+        Ok(Vec::new())
+    }
+
+    fn part_one(numbers: &Self::Input) -> Answer {
+        numbers.iter().sum::<u32>().into()
+    }
+}
+
+fn registered<S: Registered>() -> u8 {
+    S::DAY
+}
+
+fn main() {
+    registered::<Day1>();
+}
@@ -0,0 +1,23 @@
+use aoc_common::{Part, Result, Solution};
+use aoc_macros::aoc;
+
+pub struct Day1;
+
+#[aoc]
+impl Solution for Day1 {
+    const DAY: u8 = 2;
+    const PARTS: &'static [Part] = &[Part::One];
+
+    type Input = Vec<u32>;
+
+    fn parse(_input: &str) -> Result<Self::Input> {
+        Ok(Vec::new())
+    }
+}
+
+#[aoc(day = 1, part = 1)]
+fn total(numbers: &Vec<u32>) -> u32 {
+    numbers.iter().sum()
+}
+
+fn main() {}
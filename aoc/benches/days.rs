@@ -0,0 +1,62 @@
+//! Times each day's parsing and each of its parts separately, on the example
+//! and on the real input, e.g. `day12/parse/example` or `day12/part2/input`.
+//!
+//! Every part is benchmarked on the example, but only on a real input whose
+//! answer is in `inputs/answers.tsv`, see [`aoc::bench::is_benched`];
+//! `aoc bench-report` lists the parts left out. Filter with
+//! `cargo bench -p aoc -- day12/`, save a run with `-- --save-baseline <name>`
+//! and compare two with `aoc bench-report <name> <other>`.
+
+use std::path::Path;
+
+use aoc::{
+    bench::is_benched,
+    verify::{Fixture, KnownAnswers, ANSWERS_FILE},
+};
+use aoc_common::{input::Inputs, Solution};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let inputs = Inputs::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs")).year(S::YEAR);
+    let answers = KnownAnswers::load(inputs.dir().join(ANSWERS_FILE)).unwrap();
+
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    group.sample_size(20);
+    for fixture in Fixture::ALL {
+        // real inputs are personal, a checkout may not have them
+        let Ok(input) = inputs.load(S::DAY, &fixture.source()) else {
+            continue;
+        };
+        let parse = match fixture {
+            Fixture::Example => S::parse_example,
+            Fixture::Input => S::parse,
+        };
+        group.bench_function(BenchmarkId::new("parse", fixture), |b| {
+            b.iter(|| parse(black_box(&input)))
+        });
+
+        let parsed = parse(&input).unwrap();
+        for &part in S::PARTS {
+            if !is_benched(&answers, S::DAY, part, fixture) {
+                continue;
+            }
+            let name = format!("part{}", part.number());
+            group.bench_function(BenchmarkId::new(name, fixture), |b| {
+                b.iter(|| S::solve(black_box(&parsed), part))
+            });
+        }
+    }
+    group.finish();
+}
+
+// `for_each_day!`, as for the runner's table of days
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+macro_rules! group {
+    ($($solution:path),* $(,)?) => {
+        criterion_group!(days, $(bench_day::<$solution>),*);
+    };
+}
+
+for_each_day!(group);
+criterion_main!(days);
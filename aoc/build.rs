@@ -0,0 +1,99 @@
+//! Lists the day crates the runner depends on, e.g. `day7` or
+//! `year2023_day1`, as `for_each_day!` in `$OUT_DIR/days.rs`, so that adding
+//! a day is adding its dependency. A day crate of the workspace that is not a
+//! dependency is a compile error rather than a day the runner silently lacks.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let manifest = Path::new(&manifest_dir).join("Cargo.toml");
+    let workspace = Path::new(&manifest_dir).join("../Cargo.toml");
+    println!("cargo:rerun-if-changed={}", manifest.display());
+    println!("cargo:rerun-if-changed={}", workspace.display());
+
+    let mut days: Vec<(u16, u8, String)> = table(&read(&manifest), "[dependencies]")
+        .filter_map(|line| line.split('=').next())
+        .map(str::trim)
+        .filter_map(|package| day_crate(package).map(|(year, day)| (year, day, package.into())))
+        .collect();
+    days.sort();
+
+    let mut generated = String::from(
+        "macro_rules! for_each_day {\n    ($callback:ident) => {\n        $callback!(\n",
+    );
+    for (_, day, package) in &days {
+        generated += &format!("            {}::Day{},\n", package, day);
+    }
+    generated += "        );\n    };\n}\n";
+
+    for member in members(&read(&workspace)) {
+        let member = Path::new(&manifest_dir)
+            .join("..")
+            .join(member)
+            .join("Cargo.toml");
+        println!("cargo:rerun-if-changed={}", member.display());
+        let Some(package) = package_name(&read(&member)) else {
+            continue;
+        };
+        if day_crate(&package).is_some()
+            && !days.iter().any(|(_, _, dependency)| *dependency == package)
+        {
+            generated += &format!(
+                "compile_error!(\"{} is in the workspace but not a dependency of the runner, add it to aoc/Cargo.toml\");\n",
+                package
+            );
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("days.rs");
+    fs::write(out, generated).unwrap();
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("could not read {}: {}", path.display(), err))
+}
+
+/// The lines of a manifest's `header` table.
+fn table<'a>(manifest: &'a str, header: &'a str) -> impl Iterator<Item = &'a str> {
+    manifest
+        .lines()
+        .map(str::trim)
+        .skip_while(move |&line| line != header)
+        .skip(1)
+        .take_while(|line| !line.starts_with('['))
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// The year and day of a day crate's package name, with the year 0 for the
+/// default event so that its days come first.
+fn day_crate(package: &str) -> Option<(u16, u8)> {
+    let (year, day) = match package.split_once('_') {
+        Some((year, day)) => (year.strip_prefix("year")?.parse().ok()?, day),
+        None => (0, package),
+    };
+    Some((year, day.strip_prefix("day")?.parse().ok()?))
+}
+
+fn members(workspace: &str) -> Vec<String> {
+    let start = workspace
+        .find("members = [")
+        .expect("workspace should list its members");
+    let list = &workspace[start..];
+    let list = &list[..list.find(']').expect("members should be closed")];
+    list.split('"')
+        .skip(1)
+        .step_by(2)
+        .map(String::from)
+        .collect()
+}
+
+fn package_name(manifest: &str) -> Option<String> {
+    table(manifest, "[package]")
+        .find_map(|line| line.strip_prefix("name"))
+        .and_then(|name| name.split('"').nth(1))
+        .map(String::from)
+}
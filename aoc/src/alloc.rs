@@ -0,0 +1,93 @@
+//! A global allocator that counts the bytes in use, so the runner can report
+//! how much memory each part needed at its peak.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static IN_USE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+struct Counting;
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+// SAFETY: every call is forwarded to the system allocator unchanged; only
+// the counters are updated around it.
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        IN_USE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            IN_USE.fetch_sub(layout.size(), Ordering::Relaxed);
+            grow(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn grow(size: usize) {
+    let in_use = IN_USE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(in_use, Ordering::Relaxed);
+}
+
+/// Runs `f` and measures the most memory in use at once meanwhile, in bytes
+/// on top of what was already allocated. Allocations made by other threads at
+/// the same time, e.g. other tests, are counted too, and two measurements at
+/// once reset each other's peak, so the runner never makes them concurrently.
+pub fn peak_during<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = IN_USE.load(Ordering::Relaxed);
+    PEAK.store(before, Ordering::Relaxed);
+    let result = f();
+    let peak = PEAK.load(Ordering::Relaxed).saturating_sub(before);
+    (result, peak)
+}
+
+/// Serializes the tests that measure with [`peak_during`], which would
+/// otherwise reset each other's peak when the test harness runs them at once.
+#[cfg(test)]
+pub(crate) fn measuring() -> std::sync::MutexGuard<'static, ()> {
+    static MEASURING: std::sync::Mutex<()> = std::sync::Mutex::new(());
+    // a failed measuring test leaves nothing behind to protect
+    MEASURING
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peak_during() {
+        let _measuring = measuring();
+        let (sum, peak) = peak_during(|| {
+            let buffer = vec![1_u8; 1 << 20];
+            buffer.iter().map(|&byte| u32::from(byte)).sum::<u32>()
+        });
+        assert_eq!(sum, 1 << 20);
+        assert!(peak >= 1 << 20, "peak of {} bytes", peak);
+    }
+}
@@ -0,0 +1,233 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc_common::Part;
+use color_eyre::eyre::{bail, Result, WrapErr};
+use serde::Deserialize;
+
+use crate::{
+    days::Day,
+    verify::{Fixture, KnownAnswers},
+};
+
+/// The baseline criterion writes the latest run to.
+pub const LATEST: &str = "new";
+
+/// Where criterion keeps its results, found the same way criterion does.
+pub fn criterion_dir() -> PathBuf {
+    if let Some(dir) = env::var_os("CRITERION_HOME") {
+        PathBuf::from(dir)
+    } else if let Some(dir) = env::var_os("CARGO_TARGET_DIR") {
+        PathBuf::from(dir).join("criterion")
+    } else {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/criterion")
+    }
+}
+
+/// Whether `cargo bench` times a part on `fixture`: always on the example,
+/// but on the real input only once its answer is recorded, which keeps
+/// solvers that cannot finish it, or are not done yet, out of the run.
+pub fn is_benched(answers: &KnownAnswers, day: u8, part: Part, fixture: Fixture) -> bool {
+    fixture == Fixture::Example || answers.get(day, part, fixture).is_some()
+}
+
+/// The ids of the parts of `days` left out on the real input, e.g.
+/// `day12/part2/input`.
+pub fn skipped(days: &[&Day], answers: &KnownAnswers) -> BTreeSet<String> {
+    days.iter()
+        .flat_map(|day| day.parts.iter().map(move |&part| (day.day, part)))
+        .filter(|&(day, part)| !is_benched(answers, day, part, Fixture::Input))
+        .map(|(day, part)| format!("day{:02}/part{}/{}", day, part.number(), Fixture::Input))
+        .collect()
+}
+
+#[derive(Deserialize)]
+struct Benchmark {
+    full_id: String,
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    median: Estimate,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    // in nanoseconds
+    point_estimate: f64,
+}
+
+/// Median time of every benchmark saved under `baseline`, keyed by id, e.g.
+/// `day12/part2/input`.
+pub fn load_baseline(dir: &Path, baseline: &str) -> Result<BTreeMap<String, Duration>> {
+    let mut medians = BTreeMap::new();
+    collect(dir, baseline, &mut medians)
+        .wrap_err_with(|| format!("could not read benchmarks in {}", dir.display()))?;
+    if medians.is_empty() {
+        bail!(
+            "no benchmarks saved as {:?} in {}, run `cargo bench -p aoc -- --save-baseline {}` first",
+            baseline,
+            dir.display(),
+            baseline
+        );
+    }
+    Ok(medians)
+}
+
+fn collect(dir: &Path, baseline: &str, medians: &mut BTreeMap<String, Duration>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if path.ends_with(baseline) && path.join("benchmark.json").is_file() {
+            let benchmark: Benchmark =
+                serde_json::from_str(&fs::read_to_string(path.join("benchmark.json"))?)?;
+            let estimates: Estimates =
+                serde_json::from_str(&fs::read_to_string(path.join("estimates.json"))?)?;
+            let median = Duration::from_secs_f64(estimates.median.point_estimate / 1e9);
+            medians.insert(benchmark.full_id, median);
+        } else {
+            collect(&path, baseline, medians)?;
+        }
+    }
+    Ok(())
+}
+
+/// A markdown table of the medians in `baseline`, side by side with those in
+/// `compare` and the relative change when given. The `skipped` benchmarks
+/// get a row too, so that a part missing from the run does not go unnoticed.
+pub fn report(
+    baseline: (&str, &BTreeMap<String, Duration>),
+    compare: Option<(&str, &BTreeMap<String, Duration>)>,
+    skipped: &BTreeSet<String>,
+) -> String {
+    let (name, medians) = baseline;
+    let cell = |median: Option<&Duration>, id: &String| match median {
+        Some(median) => format!("{:.2?}", median),
+        None if skipped.contains(id) => "skipped".to_string(),
+        None => "-".to_string(),
+    };
+    let mut table = String::new();
+    let Some((other_name, other)) = compare else {
+        writeln!(table, "| Benchmark | {} |", name).unwrap();
+        writeln!(table, "|---|---:|").unwrap();
+        for id in medians.keys().chain(skipped).collect::<BTreeSet<_>>() {
+            writeln!(table, "| {} | {} |", id, cell(medians.get(id), id)).unwrap();
+        }
+        return table;
+    };
+
+    writeln!(table, "| Benchmark | {} | {} | Change |", name, other_name).unwrap();
+    writeln!(table, "|---|---:|---:|---:|").unwrap();
+    let ids: BTreeSet<&String> = medians.keys().chain(other.keys()).chain(skipped).collect();
+    for id in ids {
+        let change = match (medians.get(id), other.get(id)) {
+            (Some(before), Some(after)) => format!(
+                "{:+.1}%",
+                (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0
+            ),
+            _ => "-".to_string(),
+        };
+        writeln!(
+            table,
+            "| {} | {} | {} | {} |",
+            id,
+            cell(medians.get(id), id),
+            cell(other.get(id), id),
+            change
+        )
+        .unwrap();
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn save(dir: &Path, id: &str, baseline: &str, median_ns: f64) {
+        let dir = dir.join(id).join(baseline);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("benchmark.json"),
+            format!(r#"{{"group_id":"x","full_id":"{}"}}"#, id),
+        )
+        .unwrap();
+        fs::write(
+            dir.join("estimates.json"),
+            format!(
+                r#"{{"mean":{{"point_estimate":1.0}},"median":{{"point_estimate":{}}}}}"#,
+                median_ns
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_report() {
+        let dir = env::temp_dir().join(format!("aoc-bench-{}", std::process::id()));
+        save(&dir, "day12/parse/input", "main", 2_000_000.0);
+        save(&dir, "day12/parse/input", LATEST, 1_500_000.0);
+        save(&dir, "day12/part2/input", "main", 4_000.0);
+        save(&dir, "day14/part1/example", LATEST, 300.0);
+
+        let main = load_baseline(&dir, "main").unwrap();
+        let latest = load_baseline(&dir, LATEST).unwrap();
+        assert_eq!(main.len(), 2);
+        assert_eq!(latest["day12/parse/input"], Duration::from_micros(1500));
+        assert!(load_baseline(&dir, "missing").is_err());
+
+        let none = BTreeSet::new();
+        assert_eq!(
+            report(("main", &main), Some((LATEST, &latest)), &none),
+            "| Benchmark | main | new | Change |\n\
+             |---|---:|---:|---:|\n\
+             | day12/parse/input | 2.00ms | 1.50ms | -25.0% |\n\
+             | day12/part2/input | 4.00µs | - | - |\n\
+             | day14/part1/example | - | 300.00ns | - |\n"
+        );
+        assert_eq!(
+            report(("main", &main), None, &none),
+            "| Benchmark | main |\n\
+             |---|---:|\n\
+             | day12/parse/input | 2.00ms |\n\
+             | day12/part2/input | 4.00µs |\n"
+        );
+
+        let skipped = BTreeSet::from(["day15/part1/input".to_string()]);
+        assert_eq!(
+            report(("main", &main), None, &skipped),
+            "| Benchmark | main |\n\
+             |---|---:|\n\
+             | day12/parse/input | 2.00ms |\n\
+             | day12/part2/input | 4.00µs |\n\
+             | day15/part1/input | skipped |\n"
+        );
+        assert!(report(("main", &main), Some((LATEST, &latest)), &skipped)
+            .ends_with("| day15/part1/input | skipped | skipped | - |\n"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_skipped() {
+        let mut answers = KnownAnswers::default();
+        answers.insert(12, Part::One, Fixture::Input, "31");
+        assert!(is_benched(&answers, 12, Part::One, Fixture::Input));
+        assert!(is_benched(&answers, 12, Part::Two, Fixture::Example));
+        assert!(!is_benched(&answers, 12, Part::Two, Fixture::Input));
+
+        let day12 = crate::days::find(aoc_common::DEFAULT_YEAR, 12).unwrap();
+        assert_eq!(
+            skipped(&[day12], &answers),
+            BTreeSet::from(["day12/part2/input".to_string()])
+        );
+    }
+}
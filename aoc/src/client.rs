@@ -0,0 +1,518 @@
+//! Talks to adventofcode.com: downloads puzzle inputs into the inputs
+//! directory and submits answers, keeping a log of every verdict so a wrong
+//! answer is never sent twice.
+//!
+//! All network access goes through the [`Http`] trait. [`UreqHttp`] is the
+//! real backend; tests point it at a local stub server or swap it out.
+
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use aoc_common::{input::Inputs, Part};
+use color_eyre::eyre::{bail, Result, WrapErr};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = aoc_common::DEFAULT_YEAR;
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+/// The log of submitted answers, kept next to the inputs it was computed from.
+pub const GUESS_LOG: &str = "submissions.tsv";
+
+const USER_AGENT: &str = "github.com/Eikix/advent-of-code";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The two requests the client makes. Both send the session cookie.
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> Result<Response>;
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response>;
+}
+
+pub struct UreqHttp {
+    agent: ureq::Agent,
+}
+
+impl UreqHttp {
+    pub fn new() -> Self {
+        UreqHttp {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+}
+
+impl Default for UreqHttp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Http for UreqHttp {
+    fn get(&self, url: &str, session: &str) -> Result<Response> {
+        let request = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", session));
+        into_response(request.call())
+    }
+
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response> {
+        let request = self
+            .agent
+            .post(url)
+            .set("Cookie", &format!("session={}", session));
+        into_response(request.send_form(form))
+    }
+}
+
+// ureq reports 4xx/5xx as errors, but callers want to see the status and body
+fn into_response(result: std::result::Result<ureq::Response, ureq::Error>) -> Result<Response> {
+    let response = match result {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(err) => return Err(err.into()),
+    };
+    Ok(Response {
+        status: response.status(),
+        body: response.into_string()?,
+    })
+}
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which way.
+    Wrong,
+    /// Rate limited; nothing was checked.
+    Wait(Duration),
+    /// The part was already solved, so the answer was not checked.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Whether the site checked the answer, i.e. whether it belongs in the log.
+    fn is_final(self) -> bool {
+        matches!(
+            self,
+            Verdict::Correct | Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong
+        )
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(wait) => write!(f, "wait {}s", wait.as_secs()),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = color_eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => bail!("unknown verdict {:?}", s),
+        }
+    }
+}
+
+/// Reads the verdict out of the HTML page returned after a submission.
+pub fn parse_verdict(body: &str) -> Result<Verdict> {
+    if body.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if body.contains("That's not the right answer") {
+        if body.contains("your answer is too high") {
+            Ok(Verdict::TooHigh)
+        } else if body.contains("your answer is too low") {
+            Ok(Verdict::TooLow)
+        } else {
+            Ok(Verdict::Wrong)
+        }
+    } else if body.contains("You gave an answer too recently") {
+        Ok(Verdict::Wait(
+            parse_wait(body).unwrap_or(Duration::from_secs(60)),
+        ))
+    } else if body.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::AlreadySolved)
+    } else {
+        bail!("unrecognized answer page: {}", excerpt(body))
+    }
+}
+
+// "You have 4m 32s left to wait." or "You have 32s left to wait."
+fn parse_wait(body: &str) -> Option<Duration> {
+    let (before, _) = body.split_once(" left to wait")?;
+    let (_, wait) = before.rsplit_once("You have ")?;
+    let mut seconds = 0;
+    for amount in wait.split_whitespace() {
+        let (value, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+// the text of the page's <main> element, which holds the message
+fn excerpt(body: &str) -> &str {
+    let main = body
+        .split_once("<main>")
+        .and_then(|(_, rest)| rest.split_once("</main>"))
+        .map_or(body, |(main, _)| main);
+    main.trim()
+}
+
+pub struct Client<H: Http = UreqHttp> {
+    http: H,
+    session: String,
+    base_url: String,
+    year: u16,
+}
+
+impl<H: Http> Client<H> {
+    pub fn new(http: H, session: impl Into<String>) -> Self {
+        Client {
+            http,
+            session: session.into(),
+            base_url: BASE_URL.to_string(),
+            year: YEAR,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Talks about the puzzles of `year` rather than [`YEAR`]'s.
+    pub fn with_year(mut self, year: u16) -> Self {
+        self.year = year;
+        self
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day)
+    }
+
+    pub fn fetch_input(&self, day: u8) -> Result<String> {
+        let url = format!("{}/input", self.day_url(day));
+        let response = self.http.get(&url, &self.session)?;
+        if response.status != 200 {
+            bail!(
+                "fetching the day {} input failed with HTTP {}: {}",
+                day,
+                response.status,
+                response.body.trim()
+            );
+        }
+        Ok(response.body)
+    }
+
+    pub fn submit_answer(&self, day: u8, part: Part, answer: &str) -> Result<Verdict> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        let response = self.http.post_form(&url, &self.session, &form)?;
+        if response.status != 200 {
+            bail!(
+                "submitting day {} part {} failed with HTTP {}",
+                day,
+                part,
+                response.status
+            );
+        }
+        parse_verdict(&response.body)
+    }
+}
+
+/// Downloads a day's input into the inputs directory unless it is already
+/// there, and returns its path.
+pub fn fetch<H: Http>(
+    client: &Client<H>,
+    inputs: &Inputs,
+    day: u8,
+    force: bool,
+) -> Result<Fetched> {
+    let path = inputs.path(day);
+    if path.exists() && !force {
+        return Ok(Fetched::Cached(path));
+    }
+    let input = client.fetch_input(day)?;
+    fs::create_dir_all(inputs.dir())
+        .wrap_err_with(|| format!("could not create {}", inputs.dir().display()))?;
+    fs::write(&path, input).wrap_err_with(|| format!("could not write {}", path.display()))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// The outcome of [`submit`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    /// Not sent, because the log already rules the answer out.
+    Skipped(String),
+    Sent(Verdict),
+}
+
+/// Submits `answer` unless the guess log shows it is wrong, and records the
+/// site's verdict.
+pub fn submit<H: Http>(
+    client: &Client<H>,
+    log: &mut GuessLog,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Submission> {
+    if let Some(reason) = log.rule_out(day, part, answer) {
+        return Ok(Submission::Skipped(reason));
+    }
+    let verdict = client.submit_answer(day, part, answer)?;
+    if verdict.is_final() {
+        log.record(Guess {
+            day,
+            part,
+            verdict,
+            answer: answer.to_string(),
+        })?;
+    }
+    Ok(Submission::Sent(verdict))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub day: u8,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// Every answer the site has checked, one tab-separated line per guess:
+/// `day part verdict answer`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuessLog {
+    path: PathBuf,
+    guesses: Vec<Guess>,
+}
+
+impl GuessLog {
+    /// Loads the log at `path`; a missing file is an empty log.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let guesses = match fs::read_to_string(&path) {
+            Ok(log) => log
+                .lines()
+                .filter(|line| !line.is_empty())
+                .enumerate()
+                .map(|(index, line)| {
+                    parse_guess(line).wrap_err_with(|| format!("{}:{}", path.display(), index + 1))
+                })
+                .collect::<Result<Vec<Guess>>>()?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => {
+                return Err(err).wrap_err_with(|| format!("could not read {}", path.display()))
+            }
+        };
+        Ok(GuessLog { path, guesses })
+    }
+
+    pub fn guesses(&self) -> &[Guess] {
+        &self.guesses
+    }
+
+    /// Why `answer` is known to be wrong, or already accepted, if it is.
+    pub fn rule_out(&self, day: u8, part: Part, answer: &str) -> Option<String> {
+        let guesses = self
+            .guesses
+            .iter()
+            .filter(|guess| guess.day == day && guess.part == part);
+        let number: Option<i128> = answer.parse().ok();
+        for guess in guesses {
+            if guess.verdict == Verdict::Correct {
+                return Some(format!("already solved with {}", guess.answer));
+            }
+            if guess.answer == answer {
+                return Some(format!(
+                    "{} was already rejected ({})",
+                    answer, guess.verdict
+                ));
+            }
+            let bound: Option<i128> = guess.answer.parse().ok();
+            match (number, bound, guess.verdict) {
+                (Some(number), Some(bound), Verdict::TooHigh) if number >= bound => {
+                    return Some(format!("{} is too high: {} already was", answer, bound))
+                }
+                (Some(number), Some(bound), Verdict::TooLow) if number <= bound => {
+                    return Some(format!("{} is too low: {} already was", answer, bound))
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    pub fn record(&mut self, guess: Guess) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .wrap_err_with(|| format!("could not open {}", self.path.display()))?;
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}",
+            guess.day, guess.part, guess.verdict, guess.answer
+        )?;
+        self.guesses.push(guess);
+        Ok(())
+    }
+}
+
+fn parse_guess(line: &str) -> Result<Guess> {
+    let fields: Vec<&str> = line.splitn(4, '\t').collect();
+    let [day, part, verdict, answer] = fields[..] else {
+        bail!("expected 4 tab-separated fields in {:?}", line);
+    };
+    let part: u8 = part.parse()?;
+    Ok(Guess {
+        day: day.parse()?,
+        part: Part::try_from(part)?,
+        verdict: verdict.parse()?,
+        answer: answer.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_verdict() {
+        let page = |message: &str| {
+            format!(
+                "<html><main>\n<article><p>{}</p></article>\n</main></html>",
+                message
+            )
+        };
+        assert_eq!(
+            parse_verdict(&page(
+                "That's the right answer! You are one gold star closer."
+            ))
+            .unwrap(),
+            Verdict::Correct
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "That's not the right answer; your answer is too high."
+            ))
+            .unwrap(),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "That's not the right answer; your answer is too low."
+            ))
+            .unwrap(),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            parse_verdict(&page("That's not the right answer. If you're stuck, ...")).unwrap(),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            parse_verdict(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait.")).unwrap(),
+            Verdict::Wait(Duration::from_secs(272))
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "You gave an answer too recently. You have 9s left to wait."
+            ))
+            .unwrap(),
+            Verdict::Wait(Duration::from_secs(9))
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ))
+            .unwrap(),
+            Verdict::AlreadySolved
+        );
+        assert!(parse_verdict(&page("Puzzle inputs differ by user.")).is_err());
+    }
+
+    #[test]
+    fn test_rule_out() {
+        let guess = |part, verdict, answer: &str| Guess {
+            day: 11,
+            part,
+            verdict,
+            answer: answer.to_string(),
+        };
+        let log = GuessLog {
+            path: PathBuf::from("unused"),
+            guesses: vec![
+                guess(Part::One, Verdict::TooHigh, "500"),
+                guess(Part::One, Verdict::TooLow, "100"),
+                guess(Part::One, Verdict::Wrong, "250"),
+                guess(Part::Two, Verdict::Correct, "2713310158"),
+            ],
+        };
+        assert!(log.rule_out(11, Part::One, "500").is_some());
+        assert!(log.rule_out(11, Part::One, "501").is_some());
+        assert!(log.rule_out(11, Part::One, "99").is_some());
+        assert!(log.rule_out(11, Part::One, "250").is_some());
+        assert!(log.rule_out(11, Part::One, "300").is_none());
+        assert!(log.rule_out(11, Part::Two, "1").is_some());
+        assert!(log.rule_out(12, Part::One, "500").is_none());
+    }
+
+    #[test]
+    fn test_parse_guess() {
+        assert_eq!(
+            parse_guess("11\t2\ttoo-low\t42").unwrap(),
+            Guess {
+                day: 11,
+                part: Part::Two,
+                verdict: Verdict::TooLow,
+                answer: "42".to_string(),
+            }
+        );
+        assert!(parse_guess("11\t3\ttoo-low\t42").is_err());
+        assert!(parse_guess("11 2 too-low 42").is_err());
+    }
+}
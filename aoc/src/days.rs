@@ -0,0 +1,130 @@
+use std::time::{Duration, Instant};
+
+use aoc_common::{input::InputSource, Answer, Part, Registered, Solution};
+use color_eyre::eyre::Result;
+
+use crate::alloc;
+
+/// A day's solution with its solution type erased, so every day fits in one
+/// table.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub parts: &'static [Part],
+    run: fn(&str, &InputSource, &[Part]) -> Result<Run>,
+    breakdown: fn(&str, &InputSource) -> Result<Option<String>>,
+    solution: fn() -> &'static str,
+}
+
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub parse_time: Duration,
+    /// Most bytes allocated at once while parsing.
+    pub parse_peak: usize,
+    pub parts: Vec<PartRun>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Answer,
+    pub solve_time: Duration,
+    /// Most bytes allocated at once while solving, on top of the parsed input.
+    pub solve_peak: usize,
+}
+
+impl Day {
+    const fn new<S: Registered>() -> Self {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            parts: S::PARTS,
+            run: run::<S>,
+            breakdown: breakdown::<S>,
+            solution: std::any::type_name::<S>,
+        }
+    }
+
+    /// The crate the solution is in, e.g. `day13`.
+    pub fn crate_name(&self) -> &'static str {
+        let solution = (self.solution)();
+        solution.split("::").next().unwrap_or(solution)
+    }
+
+    pub fn is_implemented(&self, part: Part) -> bool {
+        self.parts.contains(&part)
+    }
+
+    /// Parses `input`, read from `source`, once, then solves each of the
+    /// requested parts.
+    pub fn run(&self, input: &str, source: &InputSource, parts: &[Part]) -> Result<Run> {
+        (self.run)(input, source, parts)
+    }
+
+    /// Parses `input`, read from `source`, and shows what the day has to say
+    /// about it besides the answers, `None` if nothing.
+    pub fn breakdown(&self, input: &str, source: &InputSource) -> Result<Option<String>> {
+        (self.breakdown)(input, source)
+    }
+}
+
+// the example is parsed as one, whatever asks for it
+fn parse<S: Solution>(input: &str, source: &InputSource) -> aoc_common::Result<S::Input> {
+    match source {
+        InputSource::Example => S::parse_example(input),
+        _ => S::parse(input),
+    }
+}
+
+fn run<S: Solution>(input: &str, source: &InputSource, parts: &[Part]) -> Result<Run> {
+    let start = Instant::now();
+    let (parsed, parse_peak) = alloc::peak_during(|| parse::<S>(input, source));
+    let parse_time = start.elapsed();
+    let parsed = parsed.map_err(|err| err.for_day(S::DAY))?;
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let (answer, solve_peak) = alloc::peak_during(|| S::solve(&parsed, part));
+            PartRun {
+                part,
+                answer,
+                solve_time: start.elapsed(),
+                solve_peak,
+            }
+        })
+        .collect();
+
+    Ok(Run {
+        parse_time,
+        parse_peak,
+        parts,
+    })
+}
+
+fn breakdown<S: Solution>(input: &str, source: &InputSource) -> Result<Option<String>> {
+    let parsed = parse::<S>(input, source).map_err(|err| err.for_day(S::DAY))?;
+    Ok(S::breakdown(&parsed))
+}
+
+// `for_each_day!`, from the day crates in the runner's dependencies
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+macro_rules! table {
+    ($($solution:path),* $(,)?) => {
+        pub const DAYS: &[Day] = &[$(Day::new::<$solution>()),*];
+    };
+}
+
+for_each_day!(table);
+
+/// The days of the `year` event, in order.
+pub fn of_year(year: u16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |entry| entry.year == year)
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    of_year(year).find(|entry| entry.day == day)
+}
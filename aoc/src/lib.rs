@@ -0,0 +1,11 @@
+//! The `aoc` runner: the table of every day's solution plus the tooling
+//! around it, used by the `aoc` binary and its integration tests.
+
+pub mod alloc;
+pub mod bench;
+pub mod client;
+pub mod days;
+pub mod record;
+pub mod scaffold;
+pub mod verify;
+pub mod watch;
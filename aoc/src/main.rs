@@ -0,0 +1,486 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+    process,
+};
+
+use aoc_common::{
+    input::{InputSource, Inputs, INPUTS_DIR_ENV},
+    parallel, Answer, Part, DEFAULT_YEAR,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use color_eyre::eyre::{bail, eyre, Result};
+
+use aoc::{
+    bench,
+    client::{
+        self, Client, Fetched, GuessLog, Submission, UreqHttp, Verdict, BASE_URL, BASE_URL_ENV,
+        GUESS_LOG, SESSION_ENV,
+    },
+    days::{self, Day, Run},
+    record::{self, Record},
+    scaffold::{self, NewDay},
+    verify::{self, Fixture, KnownAnswers, Outcome, ANSWERS_FILE},
+    watch::Watch,
+};
+
+#[derive(Parser, Debug)]
+#[command(name = "aoc", about = "Run Advent of Code solutions")]
+struct Cli {
+    /// Directory holding the default inputs, named dayNN.txt
+    #[arg(long, global = true, env = INPUTS_DIR_ENV)]
+    inputs_dir: Option<PathBuf>,
+    /// Event year; inputs of years other than 2022 are in a subdirectory of
+    /// the inputs directory named after the year
+    #[arg(long, global = true, default_value_t = DEFAULT_YEAR,
+          value_parser = clap::value_parser!(u16).range(2015..))]
+    year: u16,
+    #[command(subcommand)]
+    command: Command,
+}
+
+// what needs more than an input, for `aoc run --help`
+const DAY_EXAMPLES: &str = "\
+Day 1's planner takes the number of elves too, so it is a cargo example, reading the same inputs:
+  cargo run -p day1 --example plan -- <k> [<input>]
+      evens out the packs over k elves: the plan, its gap and the moves";
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run one day, or every day with --all
+    #[command(after_help = DAY_EXAMPLES)]
+    Run {
+        /// Day to run (1-25)
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u8>,
+        /// Only run this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Run every implemented day; all at once with the `parallel` feature
+        /// and text output, so that each day's timings include the others'
+        /// work and no peak memory is shown. JSON records are always
+        /// measured a day at a time. Parts answers.tsv marks `-` on the real
+        /// input are left out
+        #[arg(long)]
+        all: bool,
+        /// Read the input from this file instead, or from stdin with `-`
+        #[arg(short, long, conflicts_with = "all")]
+        input: Option<PathBuf>,
+        /// Run the puzzle's example instead of the real input
+        #[arg(short, long, conflicts_with = "input")]
+        example: bool,
+        /// Print the results as text, a JSON array or one JSON record per line
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Also show what the day has to say besides its answers, e.g. how
+        /// day 2 scores every round
+        #[arg(long, conflicts_with = "format")]
+        breakdown: bool,
+    },
+    /// Run a day again whenever its source, input or example changes, and
+    /// show how the answers, timings and tests changed
+    Watch {
+        /// Day to watch (1-25)
+        day: u8,
+    },
+    /// Show which days and parts are implemented
+    List,
+    /// Start a day: a crate with a test on the example, registered with the runner
+    New {
+        /// Day to start (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Summarize `cargo bench -p aoc` results, optionally against another baseline,
+    /// listing the parts skipped on the real input for lack of a recorded answer
+    BenchReport {
+        /// Criterion baseline to show, as saved with `-- --save-baseline <name>`
+        #[arg(default_value = bench::LATEST)]
+        baseline: String,
+        /// Baseline to compare it with
+        compare: Option<String>,
+        /// Where criterion keeps its results
+        #[arg(long)]
+        criterion_dir: Option<PathBuf>,
+    },
+    /// Check answers against the recorded answers.tsv in the inputs directory
+    Verify {
+        /// Only check this day
+        day: Option<u8>,
+    },
+    /// Download a day's input into the inputs directory
+    Fetch {
+        day: u8,
+        /// Download again even if the input is already there
+        #[arg(long)]
+        force: bool,
+        #[command(flatten)]
+        site: Site,
+    },
+    /// Solve a part and submit the answer
+    Submit {
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Submit this answer instead of solving, e.g. the letters drawn by day10
+        #[arg(long)]
+        answer: Option<String>,
+        /// Solve this input instead of the default one, or stdin with `-`
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        #[command(flatten)]
+        site: Site,
+    },
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+    Ndjson,
+}
+
+#[derive(Args, Debug)]
+struct Site {
+    /// Value of the adventofcode.com session cookie
+    #[arg(long, env = SESSION_ENV, hide_env_values = true)]
+    session: String,
+    #[arg(long, env = BASE_URL_ENV, default_value = BASE_URL, hide = true)]
+    base_url: String,
+}
+
+impl Site {
+    fn client(self, year: u16) -> Client {
+        Client::new(UreqHttp::new(), self.session)
+            .with_base_url(self.base_url)
+            .with_year(year)
+    }
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+    let result = run(Cli::parse());
+    // point at the bad input rather than printing a backtrace
+    if let Err(report) = &result {
+        let parse_error = report.chain().find_map(|err| match err.downcast_ref() {
+            Some(aoc_common::Error::Parse(err)) => Some(err),
+            _ => None,
+        });
+        if let Some(err) = parse_error {
+            eprint!("{}", err.render());
+            process::exit(1);
+        }
+    }
+    result
+}
+
+fn run(cli: Cli) -> Result<()> {
+    let year = cli.year;
+    let base_inputs = match cli.inputs_dir {
+        Some(dir) => Inputs::new(dir),
+        None => Inputs::from_env(),
+    };
+    let inputs = base_inputs.year(year);
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            all,
+            input,
+            example,
+            format,
+            breakdown,
+        } => {
+            let days: Vec<&Day> = if all {
+                days::of_year(year).collect()
+            } else {
+                let day = day.ok_or_else(|| eyre!("a day is required unless --all is set"))?;
+                match days::find(year, day) {
+                    Some(entry) => vec![entry],
+                    None => bail!("day {} of {} is not implemented", day, year),
+                }
+            };
+            let source = if example {
+                InputSource::Example
+            } else {
+                InputSource::from_arg(input.as_deref())
+            };
+            let parts = match part {
+                Some(part) => vec![Part::try_from(part)?],
+                None => Part::ALL.to_vec(),
+            };
+            // a run of every day should not die on a part known not to finish
+            let answers = if all && !example {
+                KnownAnswers::load(inputs.dir().join(ANSWERS_FILE))?
+            } else {
+                KnownAnswers::default()
+            };
+            let skipped =
+                |entry: &Day, part: Part| answers.is_unsolvable(entry.day, part, Fixture::Input);
+            for entry in &days {
+                for &part in parts.iter().filter(|&&part| skipped(entry, part)) {
+                    eprintln!(
+                        "Day {} part {}: skipped, {} marks it as not finishing on the input",
+                        entry.day, part, ANSWERS_FILE
+                    );
+                }
+            }
+            let run_day = |entry: &'static Day| -> Result<_> {
+                let input = inputs.load(entry.day, &source)?;
+                let parts: Vec<Part> = parts
+                    .iter()
+                    .copied()
+                    .filter(|&part| !skipped(entry, part))
+                    .collect();
+                let run = entry.run(&input, &source, &parts)?;
+                Ok((entry, input, run))
+            };
+            // with the `parallel` feature, --all runs the days at once and
+            // prints them in order once they are all done. Peak memory is
+            // counted for the whole process, so records, which carry it, are
+            // measured a day at a time, and text leaves it out of days run
+            // at once
+            let concurrent = format == Format::Text && parallel::enabled() && days.len() > 1;
+            let runs: Vec<_> = if concurrent {
+                parallel::map(days, run_day)
+            } else {
+                days.into_iter().map(run_day).collect()
+            };
+            let mut records = Vec::new();
+            for result in runs {
+                let (entry, input, run) = result?;
+                match format {
+                    Format::Text => {
+                        print_run(entry, &run, !concurrent);
+                        if breakdown {
+                            print_breakdown(entry, &input, &source, all)?;
+                        }
+                    }
+                    // NDJSON goes out a day at a time
+                    Format::Ndjson => record::write_ndjson(
+                        io::stdout().lock(),
+                        &Record::from_run(entry.day, &input, &run),
+                    )?,
+                    Format::Json => records.extend(Record::from_run(entry.day, &input, &run)),
+                }
+            }
+            if format == Format::Json {
+                record::write_json(io::stdout().lock(), &records)?;
+            }
+        }
+        Command::Watch { day } => {
+            let Some(entry) = days::find(year, day) else {
+                bail!("day {} of {} is not implemented", day, year);
+            };
+            let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+            let watch = Watch::locate(
+                &root,
+                entry,
+                base_inputs.dir(),
+                inputs.path(day),
+                inputs.example_path(day),
+            )?;
+            watch.watch()?;
+        }
+        Command::List => list(year),
+        Command::New { day } => {
+            let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+            for path in scaffold::scaffold(&root, NewDay { year, day })? {
+                let path = path.strip_prefix(&root).unwrap_or(&path);
+                println!("wrote {}", path.display());
+            }
+        }
+        Command::BenchReport {
+            baseline,
+            compare,
+            criterion_dir,
+        } => {
+            let dir = criterion_dir.unwrap_or_else(bench::criterion_dir);
+            let medians = bench::load_baseline(&dir, &baseline)?;
+            let other = match &compare {
+                Some(compare) => Some((compare.as_str(), bench::load_baseline(&dir, compare)?)),
+                None => None,
+            };
+            let answers = KnownAnswers::load(inputs.dir().join(ANSWERS_FILE))?;
+            let days: Vec<&Day> = days::of_year(year).collect();
+            print!(
+                "{}",
+                bench::report(
+                    (&baseline, &medians),
+                    other.as_ref().map(|(name, medians)| (*name, medians)),
+                    &bench::skipped(&days, &answers),
+                )
+            );
+        }
+        Command::Verify { day } => {
+            let days: Vec<&Day> = match day {
+                Some(day) => match days::find(year, day) {
+                    Some(entry) => vec![entry],
+                    None => bail!("day {} of {} is not implemented", day, year),
+                },
+                None => days::of_year(year).collect(),
+            };
+            let answers = KnownAnswers::load(inputs.dir().join(ANSWERS_FILE))?;
+            verify_days(&days, &inputs, &answers)?;
+        }
+        Command::Fetch { day, force, site } => {
+            match client::fetch(&site.client(year), &inputs, day, force)? {
+                Fetched::Cached(path) => {
+                    println!("Day {} input already at {}", day, path.display())
+                }
+                Fetched::Downloaded(path) => {
+                    println!("Day {} input saved to {}", day, path.display())
+                }
+            }
+        }
+        Command::Submit {
+            day,
+            part,
+            answer,
+            input,
+            site,
+        } => {
+            let part = Part::try_from(part)?;
+            let answer = match answer {
+                Some(answer) => answer,
+                None => solve_for_submission(&inputs, year, day, part, input)?,
+            };
+            let mut log = GuessLog::load(inputs.dir().join(GUESS_LOG))?;
+            println!("Day {} part {}: submitting {}", day, part, answer);
+            match client::submit(&site.client(year), &mut log, day, part, &answer)? {
+                Submission::Skipped(reason) => println!("  not submitted: {}", reason),
+                Submission::Sent(Verdict::Wait(wait)) => {
+                    println!("  rate limited, try again in {}s", wait.as_secs())
+                }
+                Submission::Sent(verdict) => println!("  {}", verdict),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_run(entry: &Day, run: &Run, show_peak: bool) {
+    if show_peak {
+        println!(
+            "Day {} (parse: {:.2?}, peak {} bytes)",
+            entry.day, run.parse_time, run.parse_peak
+        );
+    } else {
+        println!("Day {} (parse: {:.2?})", entry.day, run.parse_time);
+    }
+    for part_run in &run.parts {
+        let answer = part_run.answer.to_string();
+        match part_run.answer {
+            Answer::Unimplemented => println!("  Part {}: {}", part_run.part, answer),
+            _ if answer.contains('\n') => println!(
+                "  Part {} ({:.2?}):\n{}",
+                part_run.part, part_run.solve_time, answer
+            ),
+            _ => println!(
+                "  Part {}: {} ({:.2?})",
+                part_run.part, answer, part_run.solve_time
+            ),
+        }
+    }
+}
+
+// with --all, days with nothing besides their answers go without a note
+fn print_breakdown(entry: &Day, input: &str, source: &InputSource, all: bool) -> Result<()> {
+    match entry.breakdown(input, source)? {
+        Some(text) => print!("\n{}", text),
+        None if all => {}
+        None => eprintln!("Day {} shows nothing besides its answers", entry.day),
+    }
+    Ok(())
+}
+
+fn solve_for_submission(
+    inputs: &Inputs,
+    year: u16,
+    day: u8,
+    part: Part,
+    input: Option<PathBuf>,
+) -> Result<String> {
+    let entry =
+        days::find(year, day).ok_or_else(|| eyre!("day {} of {} is not implemented", day, year))?;
+    let source = InputSource::from_arg(input.as_deref());
+    let input = inputs.load(day, &source)?;
+    let run = entry.run(&input, &source, &[part])?;
+    match &run.parts[0].answer {
+        answer @ (Answer::Unimplemented | Answer::NoSolution) => {
+            bail!("day {} part {}: {}", day, part, answer)
+        }
+        Answer::Text(text) if text.contains('\n') => {
+            bail!(
+                "the answer is a picture, read it and pass it with --answer:\n{}",
+                text
+            )
+        }
+        answer => Ok(answer.to_string()),
+    }
+}
+
+fn verify_days(days: &[&Day], inputs: &Inputs, answers: &KnownAnswers) -> Result<()> {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    println!("Day  Part  Input    Result");
+    for entry in days {
+        for check in verify::verify(entry, inputs, answers)? {
+            let result = match &check.outcome {
+                Outcome::Pass => {
+                    passed += 1;
+                    "pass"
+                }
+                Outcome::Fail { .. } => {
+                    failed += 1;
+                    "FAIL"
+                }
+                Outcome::Missing => {
+                    missing += 1;
+                    "missing"
+                }
+            };
+            println!(
+                "{:>3}  {:>4}  {:<7}  {}",
+                check.day,
+                check.part.number(),
+                check.fixture,
+                result
+            );
+            if let Outcome::Fail { expected, actual } = &check.outcome {
+                println!("  expected: {}", expected.replace('\n', "\n            "));
+                println!("  got:      {}", actual.replace('\n', "\n            "));
+            }
+        }
+    }
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    if failed > 0 {
+        bail!(
+            "{} of the answers in {} no longer match",
+            failed,
+            ANSWERS_FILE
+        );
+    }
+    Ok(())
+}
+
+fn list(year: u16) {
+    println!("Day  Part 1  Part 2");
+    for entry in days::of_year(year) {
+        println!(
+            "{:>3}  {:<6}  {:<6}",
+            entry.day,
+            status(entry.is_implemented(Part::One)),
+            status(entry.is_implemented(Part::Two))
+        );
+    }
+}
+
+fn status(implemented: bool) -> &'static str {
+    if implemented {
+        "yes"
+    } else {
+        "no"
+    }
+}
@@ -0,0 +1,158 @@
+//! Machine-readable results: one [`Record`] per part run, written as a JSON
+//! array or as NDJSON, one record per line, for scripts and dashboards.
+
+use std::io::{self, Write};
+
+use aoc_common::Answer;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::days::Run;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// A number or text when solved, `null` otherwise; see `status`.
+    pub answer: serde_json::Value,
+    pub status: Status,
+    /// Time to parse the input, shared by both parts of a run.
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    /// Most bytes allocated at once while solving, on top of the parsed input.
+    pub peak_alloc_bytes: usize,
+    /// Most bytes allocated at once while parsing, shared like `parse_ns`.
+    pub parse_peak_alloc_bytes: usize,
+    /// SHA-256 of the input, in hex, to tell which input an answer is for.
+    pub input_hash: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Solved,
+    NoSolution,
+    Unimplemented,
+}
+
+impl Record {
+    /// The records of every part in `run`, a run of `day` on `input`.
+    pub fn from_run(day: u8, input: &str, run: &Run) -> Vec<Record> {
+        let input_hash = input_hash(input);
+        run.parts
+            .iter()
+            .map(|part_run| {
+                let (answer, status) = match &part_run.answer {
+                    Answer::Number(number) => (number_value(*number), Status::Solved),
+                    Answer::Text(text) => (text.as_str().into(), Status::Solved),
+                    Answer::NoSolution => (serde_json::Value::Null, Status::NoSolution),
+                    Answer::Unimplemented => (serde_json::Value::Null, Status::Unimplemented),
+                };
+                Record {
+                    day,
+                    part: part_run.part.number(),
+                    answer,
+                    status,
+                    parse_ns: nanos(run.parse_time),
+                    solve_ns: nanos(part_run.solve_time),
+                    peak_alloc_bytes: part_run.solve_peak,
+                    parse_peak_alloc_bytes: run.parse_peak,
+                    input_hash: input_hash.clone(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Writes `records` as one pretty-printed JSON array.
+pub fn write_json(mut out: impl Write, records: &[Record]) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut out, records)?;
+    writeln!(out)
+}
+
+/// Writes each of `records` as JSON on a line of its own.
+pub fn write_ndjson(mut out: impl Write, records: &[Record]) -> io::Result<()> {
+    for record in records {
+        serde_json::to_writer(&mut out, record)?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+// JSON has no 128-bit numbers, so answers that big are written as text
+fn number_value(number: i128) -> serde_json::Value {
+    i64::try_from(number).map_or_else(|_| number.to_string().into(), Into::into)
+}
+
+fn nanos(duration: std::time::Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{alloc, days};
+    use aoc_common::{input::InputSource, Part, DEFAULT_YEAR};
+
+    #[test]
+    fn test_records() {
+        let _measuring = alloc::measuring();
+        let input = include_str!("../../inputs/examples/day12.txt");
+        let run = days::find(DEFAULT_YEAR, 12)
+            .unwrap()
+            .run(input, &InputSource::Example, &Part::ALL)
+            .unwrap();
+        let records = Record::from_run(12, input, &run);
+        assert_eq!(records.len(), 2);
+        assert_eq!((records[0].part, records[1].part), (1, 2));
+        assert_eq!(records[0].answer, serde_json::json!(31));
+        assert_eq!(records[1].status, Status::Solved);
+        assert_eq!(records[0].input_hash, input_hash(input));
+        assert_eq!(records[0].input_hash.len(), 64);
+
+        let mut ndjson = Vec::new();
+        write_ndjson(&mut ndjson, &records).unwrap();
+        let lines: Vec<serde_json::Value> = String::from_utf8(ndjson)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1]["answer"], 29);
+        assert_eq!(lines[1]["status"], "solved");
+        // parsing the heightmap allocates it
+        assert!(records[0].parse_peak_alloc_bytes > 0);
+        let fields = [
+            "day",
+            "parse_ns",
+            "solve_ns",
+            "peak_alloc_bytes",
+            "parse_peak_alloc_bytes",
+        ];
+        for field in fields {
+            assert!(lines[0][field].is_u64(), "{} is missing", field);
+        }
+
+        let mut json = Vec::new();
+        write_json(&mut json, &records).unwrap();
+        let array: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(array.as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_answers() {
+        assert_eq!(input_hash("")[..8], *"e3b0c442");
+        assert_eq!(number_value(-3), serde_json::json!(-3));
+        assert_eq!(
+            number_value(i128::from(i64::MAX) + 1),
+            serde_json::json!("9223372036854775808")
+        );
+    }
+}
@@ -0,0 +1,346 @@
+//! `aoc new`: starts a day from the same template every time, a crate with
+//! the [`Solution`](aoc_common::Solution) skeleton and a test on the example
+//! fixture, registered with the workspace and the runner. Being a dependency
+//! of the runner puts it in the table of days and the benchmarks.
+//!
+//! Days of [`DEFAULT_YEAR`] live in `dayN/` at the workspace root, days of
+//! other years in `yearYYYY/dayN/`, with their inputs in `inputs/YYYY/`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::DEFAULT_YEAR;
+use color_eyre::eyre::{bail, eyre, Result, WrapErr};
+
+/// A day to scaffold, and where its files go relative to the workspace root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NewDay {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl NewDay {
+    /// The crate's directory, e.g. `day16` or `year2023/day1`.
+    pub fn crate_dir(&self) -> PathBuf {
+        match self.year {
+            DEFAULT_YEAR => PathBuf::from(format!("day{}", self.day)),
+            year => Path::new(&format!("year{}", year)).join(format!("day{}", self.day)),
+        }
+    }
+
+    /// The package name, e.g. `day16` or `year2023_day1`.
+    pub fn package(&self) -> String {
+        match self.year {
+            DEFAULT_YEAR => format!("day{}", self.day),
+            year => format!("year{}_day{}", year, self.day),
+        }
+    }
+
+    /// The example input the generated test reads, e.g.
+    /// `inputs/examples/day16.txt`.
+    pub fn example_fixture(&self) -> PathBuf {
+        let inputs = match self.year {
+            DEFAULT_YEAR => PathBuf::from("inputs"),
+            year => Path::new("inputs").join(year.to_string()),
+        };
+        inputs
+            .join("examples")
+            .join(format!("day{:02}.txt", self.day))
+    }
+
+    fn solution(&self) -> String {
+        format!("Day{}", self.day)
+    }
+
+    // the way back up to the workspace root from the crate's directory
+    fn root_from_crate(&self) -> String {
+        "../".repeat(self.crate_dir().components().count())
+    }
+
+    fn manifest(&self) -> String {
+        format!(
+            "[package]\n\
+             name = \"{}\"\n\
+             version = \"0.1.0\"\n\
+             edition = \"2021\"\n\
+             \n\
+             [dependencies]\n\
+             aoc-common = {{ path = \"{root}aoc-common\" }}\n\
+             aoc-macros = {{ path = \"{root}aoc-macros\" }}\n",
+            self.package(),
+            root = self.root_from_crate()
+        )
+    }
+
+    fn lib(&self) -> String {
+        let year = match self.year {
+            DEFAULT_YEAR => String::new(),
+            year => format!("    const YEAR: u16 = {};\n\n", year),
+        };
+        let fixture = self.example_fixture();
+        // from src/lib.rs rather than from the crate
+        let root = format!("../{}", self.root_from_crate());
+        format!(
+            r#"use aoc_common::{{Part, Result, Solution}};
+use aoc_macros::aoc;
+
+pub struct {solution};
+
+#[aoc(day = {day})]
+impl Solution for {solution} {{
+{year}    // add each part here once a fn with #[aoc(day = {day}, part = ..)] solves it
+    const PARTS: &'static [Part] = &[];
+
+    // one string per line, until the puzzle calls for something better
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {{
+        Ok(input.lines().map(str::to_string).collect())
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use aoc_common::Answer;
+
+    #[test]
+    fn test_example() {{
+        let input = include_str!("{root}{fixture}");
+        let parsed = {solution}::parse_example(input).unwrap();
+        // the answers the puzzle gives for its example, once solved
+        assert_eq!({solution}::solve(&parsed, Part::One), Answer::Unimplemented);
+        assert_eq!({solution}::solve(&parsed, Part::Two), Answer::Unimplemented);
+    }}
+}}
+"#,
+            solution = self.solution(),
+            day = self.day,
+            year = year,
+            root = root,
+            fixture = fixture.display(),
+        )
+    }
+}
+
+/// Creates the day's crate and example fixture under the workspace `root`
+/// and registers the crate with the workspace and as a dependency of the
+/// runner. Returns the files written, created ones first.
+pub fn scaffold(root: &Path, new: NewDay) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&new.day) {
+        bail!("there is no day {}, puzzles go from 1 to 25", new.day);
+    }
+    let crate_dir = root.join(new.crate_dir());
+    if crate_dir.exists() {
+        bail!(
+            "{} already exists, day {} of {} has been started",
+            crate_dir.display(),
+            new.day,
+            new.year
+        );
+    }
+
+    // check every registration fits before writing anything
+    let package = new.package();
+    let crate_path = new.crate_dir().display().to_string();
+    let registrations = [
+        (
+            PathBuf::from("Cargo.toml"),
+            "members = [",
+            "\n]",
+            format!("    \"{}\",", crate_path),
+        ),
+        (
+            Path::new("aoc").join("Cargo.toml"),
+            "[dependencies]",
+            "\n\n",
+            format!("{} = {{ path = \"../{}\" }}", package, crate_path),
+        ),
+    ];
+    let mut updated = Vec::new();
+    for (file, open, close, line) in &registrations {
+        let path = root.join(file);
+        let text = read(&path)?;
+        let text =
+            register(&text, open, close, line).wrap_err_with(|| path.display().to_string())?;
+        updated.push((path, text));
+    }
+
+    let mut written = Vec::new();
+    let mut create = |path: PathBuf, contents: String| -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .wrap_err_with(|| format!("could not create {}", dir.display()))?;
+        }
+        fs::write(&path, contents)
+            .wrap_err_with(|| format!("could not write {}", path.display()))?;
+        written.push(path);
+        Ok(())
+    };
+    create(crate_dir.join("Cargo.toml"), new.manifest())?;
+    create(crate_dir.join("src").join("lib.rs"), new.lib())?;
+    // an example someone already saved is kept
+    let fixture = root.join(new.example_fixture());
+    if !fixture.exists() {
+        create(fixture, String::new())?;
+    }
+    for (path, text) in updated {
+        create(path, text)?;
+    }
+    Ok(written)
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).wrap_err_with(|| format!("could not read {}", path.display()))
+}
+
+// Adds `line` as the last entry of the list that starts at `open` and ends
+// at the first `close` after it
+fn register(text: &str, open: &str, close: &str, line: &str) -> Result<String> {
+    let start = text
+        .find(open)
+        .ok_or_else(|| eyre!("could not find {:?} to register the day in", open))?;
+    let end = text[start..]
+        .find(close)
+        .map(|end| start + end)
+        .ok_or_else(|| eyre!("the list after {:?} never ends", open))?;
+    if text[start..end].lines().any(|existing| existing == line) {
+        bail!("{:?} is already registered", line.trim());
+    }
+    Ok(format!("{}\n{}{}", &text[..end], line, &text[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const WORKSPACE: &str = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day1\",\n]\n";
+    const RUNNER: &str = "[package]\nname = \"aoc\"\n\n[dependencies]\nday1 = { path = \"../day1\" }\n\n[dev-dependencies]\n";
+
+    // a workspace with the runner manifest `scaffold` edits, and one day
+    fn workspace(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc")).unwrap();
+        fs::write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), RUNNER).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = workspace("day");
+        let new = NewDay {
+            year: DEFAULT_YEAR,
+            day: 16,
+        };
+        let written = scaffold(&root, new).unwrap();
+        assert_eq!(written.len(), 5);
+        assert_eq!(
+            written[..3],
+            [
+                root.join("day16/Cargo.toml"),
+                root.join("day16/src/lib.rs"),
+                root.join("inputs/examples/day16.txt"),
+            ]
+        );
+
+        let lib = read(&root.join("day16/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day16;"));
+        assert!(lib.contains("#[aoc(day = 16)]\nimpl Solution for Day16 {"));
+        assert!(!lib.contains("const YEAR"));
+        assert!(lib.contains("include_str!(\"../../inputs/examples/day16.txt\")"));
+        let manifest = read(&root.join("day16/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"day16\""));
+        assert!(manifest.contains("path = \"../aoc-common\""));
+        assert!(manifest.contains("path = \"../aoc-macros\""));
+
+        assert_eq!(
+            read(&root.join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day1\",\n    \"day16\",\n]\n"
+        );
+        assert!(read(&root.join("aoc/Cargo.toml"))
+            .unwrap()
+            .contains("day1 = { path = \"../day1\" }\nday16 = { path = \"../day16\" }\n\n"));
+
+        // a day is only started once
+        let err = scaffold(&root, new).unwrap_err();
+        assert!(err.to_string().contains("already exists"), "{}", err);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_scaffold_year() {
+        let root = workspace("year");
+        fs::create_dir_all(root.join("inputs/2023/examples")).unwrap();
+        fs::write(root.join("inputs/2023/examples/day01.txt"), "1abc2\n").unwrap();
+        let new = NewDay { year: 2023, day: 1 };
+        scaffold(&root, new).unwrap();
+
+        let lib = read(&root.join("year2023/day1/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day1;"));
+        assert!(lib.contains("const YEAR: u16 = 2023;"));
+        assert!(lib.contains("include_str!(\"../../../inputs/2023/examples/day01.txt\")"));
+        let manifest = read(&root.join("year2023/day1/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"year2023_day1\""));
+        assert!(manifest.contains("path = \"../../aoc-macros\""));
+        // the example was already saved
+        assert_eq!(
+            read(&root.join("inputs/2023/examples/day01.txt")).unwrap(),
+            "1abc2\n"
+        );
+        assert!(read(&root.join("Cargo.toml"))
+            .unwrap()
+            .contains("    \"year2023/day1\",\n]"));
+        assert!(read(&root.join("aoc/Cargo.toml"))
+            .unwrap()
+            .contains("year2023_day1 = { path = \"../year2023/day1\" }\n"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_scaffold_errors() {
+        let root = workspace("errors");
+        let err = scaffold(
+            &root,
+            NewDay {
+                year: DEFAULT_YEAR,
+                day: 26,
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("no day 26"));
+
+        // nothing is written when the day cannot be registered
+        fs::write(root.join("aoc/Cargo.toml"), "[package]\nname = \"aoc\"\n").unwrap();
+        let err = scaffold(
+            &root,
+            NewDay {
+                year: DEFAULT_YEAR,
+                day: 16,
+            },
+        )
+        .unwrap_err();
+        assert!(format!("{:#}", err).contains("[dependencies]"), "{:#}", err);
+        assert!(!root.join("day16").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_register() {
+        let text = "a = [\n    1,\n]\nb = [\n]\n";
+        assert_eq!(
+            register(text, "a = [", "\n]", "    2,").unwrap(),
+            "a = [\n    1,\n    2,\n]\nb = [\n]\n"
+        );
+        assert_eq!(
+            register(text, "b = [", "\n]", "    3,").unwrap(),
+            "a = [\n    1,\n]\nb = [\n    3,\n]\n"
+        );
+        assert!(register(text, "a = [", "\n]", "    1,").is_err());
+        assert!(register(text, "c = [", "\n]", "    1,").is_err());
+    }
+}
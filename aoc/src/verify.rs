@@ -0,0 +1,237 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt, fs,
+    path::Path,
+    str::FromStr,
+};
+
+use aoc_common::{
+    input::{InputSource, Inputs},
+    Part,
+};
+use color_eyre::eyre::{bail, Result, WrapErr};
+
+use crate::days::Day;
+
+/// Name of the recorded answers file in the inputs directory.
+pub const ANSWERS_FILE: &str = "answers.tsv";
+
+/// Which of a day's inputs an answer was recorded for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Fixture {
+    Example,
+    Input,
+}
+
+impl Fixture {
+    pub const ALL: [Fixture; 2] = [Fixture::Example, Fixture::Input];
+
+    pub fn source(self) -> InputSource {
+        match self {
+            Fixture::Example => InputSource::Example,
+            Fixture::Input => InputSource::Default,
+        }
+    }
+}
+
+impl fmt::Display for Fixture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fixture::Example => f.pad("example"),
+            Fixture::Input => f.pad("input"),
+        }
+    }
+}
+
+impl FromStr for Fixture {
+    type Err = color_eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "example" => Ok(Fixture::Example),
+            "input" => Ok(Fixture::Input),
+            _ => bail!("unknown input {:?}, expected example or input", s),
+        }
+    }
+}
+
+/// Answers known to be right, one tab-separated line per part and input:
+/// `day part example|input answer`. Newlines in picture answers are written
+/// as `\n`, and lines starting with `#` are comments. An answer of `-` says
+/// the solver cannot finish on that input, e.g. runs out of memory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KnownAnswers {
+    answers: BTreeMap<(u8, Part, Fixture), String>,
+    unsolvable: BTreeSet<(u8, Part, Fixture)>,
+}
+
+impl KnownAnswers {
+    /// Loads the answers at `path`; a missing file records nothing.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(text) => text.parse().wrap_err_with(|| path.display().to_string()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(KnownAnswers::default()),
+            Err(err) => Err(err).wrap_err_with(|| format!("could not read {}", path.display())),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: Part, fixture: Fixture) -> Option<&str> {
+        self.answers.get(&(day, part, fixture)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, fixture: Fixture, answer: impl Into<String>) {
+        self.answers.insert((day, part, fixture), answer.into());
+    }
+
+    /// Whether the part is recorded as one its solver cannot finish on this
+    /// input, so runs of every day should leave it out.
+    pub fn is_unsolvable(&self, day: u8, part: Part, fixture: Fixture) -> bool {
+        self.unsolvable.contains(&(day, part, fixture))
+    }
+}
+
+impl FromStr for KnownAnswers {
+    type Err = color_eyre::Report;
+
+    fn from_str(text: &str) -> Result<Self> {
+        let mut answers = KnownAnswers::default();
+        for (index, line) in text.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.splitn(4, '\t').collect();
+            let [day, part, fixture, answer] = fields[..] else {
+                bail!(
+                    "line {}: expected 4 tab-separated fields in {:?}",
+                    index + 1,
+                    line
+                );
+            };
+            let parsed = (|| -> Result<(u8, Part, Fixture)> {
+                let part: u8 = part.parse()?;
+                Ok((day.parse()?, Part::try_from(part)?, fixture.parse()?))
+            })();
+            let (day, part, fixture) = parsed.wrap_err_with(|| format!("line {}", index + 1))?;
+            let key = (day, part, fixture);
+            if answers.answers.contains_key(&key) || answers.unsolvable.contains(&key) {
+                bail!(
+                    "line {}: day {} part {} {} is recorded twice",
+                    index + 1,
+                    day,
+                    part,
+                    fixture
+                );
+            }
+            if answer == "-" {
+                answers.unsolvable.insert(key);
+            } else {
+                answers.insert(day, part, fixture, answer.replace("\\n", "\n"));
+            }
+        }
+        Ok(answers)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// The part is implemented but no answer is recorded for this input.
+    Missing,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub fixture: Fixture,
+    pub outcome: Outcome,
+}
+
+/// Solves every part of `day` that has a recorded answer and compares the
+/// results. Parts without one are reported as missing without being run, so
+/// a slow or unfinished solver never holds up the rest of the suite.
+pub fn verify(day: &Day, inputs: &Inputs, answers: &KnownAnswers) -> Result<Vec<Check>> {
+    let mut checks = Vec::new();
+    for fixture in Fixture::ALL {
+        let recorded: Vec<Part> = Part::ALL
+            .into_iter()
+            .filter(|&part| answers.get(day.day, part, fixture).is_some())
+            .collect();
+        let run = if recorded.is_empty() {
+            None
+        } else {
+            let input = inputs.load(day.day, &fixture.source())?;
+            Some(
+                day.run(&input, &fixture.source(), &recorded)
+                    .wrap_err_with(|| format!("day {} {}", day.day, fixture))?,
+            )
+        };
+
+        for part in Part::ALL {
+            let actual = run
+                .iter()
+                .flat_map(|run| &run.parts)
+                .find(|part_run| part_run.part == part)
+                .map(|part_run| part_run.answer.to_string());
+            let outcome = match (answers.get(day.day, part, fixture), actual) {
+                (Some(expected), Some(actual)) if expected == actual => Outcome::Pass,
+                (Some(expected), Some(actual)) => Outcome::Fail {
+                    expected: expected.to_string(),
+                    actual,
+                },
+                _ if day.is_implemented(part) => Outcome::Missing,
+                _ => continue,
+            };
+            checks.push(Check {
+                day: day.day,
+                part,
+                fixture,
+                outcome,
+            });
+        }
+    }
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers: KnownAnswers = "# day\tpart\tinput\tanswer\n\
+                                     5\t1\texample\tCMZ\n\
+                                     \n\
+                                     10\t2\tinput\t#..#\\n.##.\n"
+            .parse()
+            .unwrap();
+        assert_eq!(answers.get(5, Part::One, Fixture::Example), Some("CMZ"));
+        assert_eq!(answers.get(5, Part::One, Fixture::Input), None);
+        assert_eq!(
+            answers.get(10, Part::Two, Fixture::Input),
+            Some("#..#\n.##.")
+        );
+
+        assert!(!answers.is_unsolvable(5, Part::One, Fixture::Example));
+
+        let answers: KnownAnswers = "15\t1\texample\t26\n15\t1\tinput\t-".parse().unwrap();
+        assert_eq!(answers.get(15, Part::One, Fixture::Input), None);
+        assert!(answers.is_unsolvable(15, Part::One, Fixture::Input));
+        assert!(!answers.is_unsolvable(15, Part::One, Fixture::Example));
+        assert!("15\t1\tinput\t-\n15\t1\tinput\t26"
+            .parse::<KnownAnswers>()
+            .is_err());
+
+        assert!("5\t1\texample".parse::<KnownAnswers>().is_err());
+        assert!("5\t3\texample\tCMZ".parse::<KnownAnswers>().is_err());
+        assert!("5\t1\treal\tCMZ".parse::<KnownAnswers>().is_err());
+        assert!("5\t1\texample\tCMZ\n5\t1\texample\tMCD"
+            .parse::<KnownAnswers>()
+            .is_err());
+    }
+}
@@ -0,0 +1,575 @@
+//! `aoc watch`: re-runs a day whenever its source, input or example changes,
+//! and prints what changed since the last run.
+//!
+//! Every run goes through cargo, so that edits to the source are built
+//! before they run: `aoc run --format json` on the input and on the example,
+//! then the day's unit tests. Changes are picked up from the file system's
+//! own notifications, nothing is polled.
+
+use std::{
+    collections::HashMap,
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::mpsc,
+    time::Duration,
+};
+
+use color_eyre::eyre::{bail, eyre, Result, WrapErr};
+use notify::{
+    event::{AccessKind, AccessMode},
+    EventKind, RecursiveMode, Watcher,
+};
+
+use crate::{
+    days::Day,
+    record::{Record, Status},
+};
+
+/// Edits arriving this close together, e.g. an editor's save, make one run.
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// A day being watched, and where its pieces are.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Watch {
+    pub year: u16,
+    pub day: u8,
+    /// The workspace, where cargo runs.
+    pub root: PathBuf,
+    /// The day's package, e.g. `day13`, and the directory it is in.
+    pub package: String,
+    pub crate_dir: PathBuf,
+    /// The inputs directory `aoc run` is given, before the year is applied.
+    pub inputs_dir: PathBuf,
+    /// The real input and the example, as `Inputs::path` and
+    /// `Inputs::example_path` give them for the year.
+    pub input: PathBuf,
+    pub example: PathBuf,
+}
+
+impl Watch {
+    /// Finds the crate of `day` in the workspace at `root` with
+    /// `cargo metadata`. `input` and `example` need not exist yet.
+    pub fn locate(
+        root: &Path,
+        day: &Day,
+        inputs_dir: &Path,
+        input: PathBuf,
+        example: PathBuf,
+    ) -> Result<Self> {
+        let output = Command::new(cargo())
+            .args(["metadata", "--no-deps", "--format-version", "1"])
+            .current_dir(root)
+            .output()
+            .wrap_err("could not run cargo metadata")?;
+        if !output.status.success() {
+            bail!("cargo metadata failed: {}", error_line(&output.stderr));
+        }
+        let metadata: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+        let (package, manifest) = find_package(&metadata, day.crate_name()).ok_or_else(|| {
+            eyre!(
+                "no package in the workspace has the crate {}",
+                day.crate_name()
+            )
+        })?;
+        Ok(Watch {
+            year: day.year,
+            day: day.day,
+            root: canonical(root),
+            package,
+            crate_dir: manifest.parent().unwrap_or(root).to_path_buf(),
+            inputs_dir: inputs_dir.to_path_buf(),
+            input: canonical(&input),
+            example: canonical(&example),
+        })
+    }
+
+    /// Whether a change to `path` calls for a new run: the crate's manifest
+    /// and sources, the input and the example. Editors' swap and temporary
+    /// files next to the sources are not.
+    pub fn is_watched(&self, path: &Path) -> bool {
+        path == self.input
+            || path == self.example
+            || path == self.crate_dir.join("Cargo.toml")
+            || (path.starts_with(self.crate_dir.join("src"))
+                && path.extension().is_some_and(|extension| extension == "rs"))
+    }
+
+    /// Runs the day on its input and its example, then its unit tests, all
+    /// through cargo.
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            input: self.run(false),
+            example: self.run(true),
+            tests: self.test(),
+        }
+    }
+
+    fn run(&self, example: bool) -> Result<Vec<Record>, String> {
+        let mut command = Command::new(cargo());
+        command
+            .args(["run", "-q", "--release", "-p", "aoc", "--"])
+            .arg("--year")
+            .arg(self.year.to_string())
+            .arg("--inputs-dir")
+            .arg(&self.inputs_dir)
+            .args(["run", &self.day.to_string(), "--format", "json"]);
+        if example {
+            command.arg("--example");
+        }
+        let output = command
+            .current_dir(&self.root)
+            .output()
+            .map_err(|err| format!("could not run cargo: {}", err))?;
+        if !output.status.success() {
+            return Err(error_line(&output.stderr));
+        }
+        serde_json::from_slice(&output.stdout).map_err(|err| err.to_string())
+    }
+
+    // the day's unit tests, leaving out the slower generator ones
+    fn test(&self) -> Result<Tests, String> {
+        let output = Command::new(cargo())
+            .args(["test", "-q", "-p", &self.package, "--lib"])
+            .args(["--", "--skip", "generate::"])
+            .current_dir(&self.root)
+            .output()
+            .map_err(|err| format!("could not run cargo: {}", err))?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        match Tests::parse(&stdout) {
+            Some(tests) => Ok(tests),
+            None => Err(error_line(&output.stderr)),
+        }
+    }
+
+    /// Runs the day now and again after every change, printing what differs
+    /// from the run before. Only returns on an error.
+    pub fn watch(&self) -> Result<()> {
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        // the directories, since editors often save by replacing the file
+        watcher.watch(&self.crate_dir, RecursiveMode::Recursive)?;
+        for file in [&self.input, &self.example] {
+            let dir = file.parent().unwrap_or(Path::new("."));
+            watcher
+                .watch(dir, RecursiveMode::NonRecursive)
+                .wrap_err_with(|| format!("could not watch {}", dir.display()))?;
+        }
+
+        println!(
+            "Watching day {} ({}), {} and {}",
+            self.day,
+            self.crate_dir.display(),
+            self.input.display(),
+            self.example.display()
+        );
+        let mut previous = self.snapshot();
+        print!("{}", report(None, &previous));
+        loop {
+            let mut changed = Vec::new();
+            let mut collect = |event: notify::Result<notify::Event>| -> Result<()> {
+                let event = event?;
+                if is_change(&event.kind) {
+                    for path in event.paths {
+                        if self.is_watched(&path) && !changed.contains(&path) {
+                            changed.push(path);
+                        }
+                    }
+                }
+                Ok(())
+            };
+            collect(events.recv()?)?;
+            while let Ok(event) = events.recv_timeout(SETTLE) {
+                collect(event)?;
+            }
+            if changed.is_empty() {
+                continue;
+            }
+
+            let names: Vec<String> = changed
+                .iter()
+                .map(|path| {
+                    let path = path.strip_prefix(&self.root).unwrap_or(path);
+                    path.display().to_string()
+                })
+                .collect();
+            println!("\nChanged: {}", names.join(", "));
+            let current = self.snapshot();
+            print!("{}", report(Some(&previous), &current));
+            previous = current;
+        }
+    }
+}
+
+// `path` without `..` and symlinks, as the notifications name it, even if
+// only its directory exists
+fn canonical(path: &Path) -> PathBuf {
+    if let Ok(path) = path.canonicalize() {
+        return path;
+    }
+    match (path.parent(), path.file_name()) {
+        (Some(dir), Some(name)) => canonical(dir).join(name),
+        _ => path.to_path_buf(),
+    }
+}
+
+fn cargo() -> String {
+    env::var("CARGO").unwrap_or_else(|_| "cargo".to_string())
+}
+
+// The package whose library is `crate_name`, and its manifest
+fn find_package(metadata: &serde_json::Value, crate_name: &str) -> Option<(String, PathBuf)> {
+    metadata["packages"].as_array()?.iter().find_map(|package| {
+        let has_crate = package["targets"].as_array()?.iter().any(|target| {
+            target["name"] == crate_name
+                && target["kind"]
+                    .as_array()
+                    .is_some_and(|kinds| kinds.iter().any(|kind| kind == "lib"))
+        });
+        if !has_crate {
+            return None;
+        }
+        Some((
+            package["name"].as_str()?.to_string(),
+            PathBuf::from(package["manifest_path"].as_str()?),
+        ))
+    })
+}
+
+// Writes, creations, removals and renames; not reads
+fn is_change(kind: &EventKind) -> bool {
+    match kind {
+        EventKind::Access(AccessKind::Close(AccessMode::Write)) => true,
+        EventKind::Access(_) => false,
+        _ => true,
+    }
+}
+
+// The line of a failed cargo run worth showing: the error the runner
+// reported, the compiler's first error, or else the last line
+fn error_line(stderr: &[u8]) -> String {
+    let stderr = strip_ansi(&String::from_utf8_lossy(stderr));
+    let lines: Vec<&str> = stderr
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    if let Some(line) = lines.iter().find(|line| line.starts_with("error")) {
+        return line.to_string();
+    }
+    if let Some(index) = lines.iter().position(|&line| line == "Error:") {
+        if let Some(cause) = lines.get(index + 1) {
+            let cause = cause.split_once(": ").map_or(*cause, |(_, cause)| cause);
+            return cause.to_string();
+        }
+    }
+    lines.last().unwrap_or(&"failed").to_string()
+}
+
+fn strip_ansi(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // up to the letter ending the escape sequence
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            plain.push(c);
+        }
+    }
+    plain
+}
+
+/// What one run of a watched day gave, or why it failed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    pub input: Result<Vec<Record>, String>,
+    pub example: Result<Vec<Record>, String>,
+    pub tests: Result<Tests, String>,
+}
+
+/// The day's unit tests that passed and failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Tests {
+    pub passed: usize,
+    pub failed: usize,
+}
+
+impl Tests {
+    /// Adds up the `test result:` lines of `cargo test` output; `None` if
+    /// there are none, e.g. when the tests did not build.
+    pub fn parse(stdout: &str) -> Option<Self> {
+        let mut tests = None;
+        for line in stdout.lines() {
+            let Some(result) = line.strip_prefix("test result: ") else {
+                continue;
+            };
+            let count = |what: &str| {
+                result
+                    .split([';', '.'])
+                    .find_map(|field| field.trim().strip_suffix(what))
+                    .and_then(|count| count.trim().parse::<usize>().ok())
+                    .unwrap_or(0)
+            };
+            let total: &mut Tests = tests.get_or_insert_with(Tests::default);
+            total.passed += count(" passed");
+            total.failed += count(" failed");
+        }
+        tests
+    }
+}
+
+/// One line per part of each run, with the answer and solve time, and how
+/// both changed since `previous`; then the tests.
+pub fn report(previous: Option<&Snapshot>, current: &Snapshot) -> String {
+    let mut report = String::new();
+    for (name, before, now) in [
+        ("input", previous.map(|p| &p.input), &current.input),
+        ("example", previous.map(|p| &p.example), &current.example),
+    ] {
+        let records = match now {
+            Ok(records) => records,
+            Err(err) => {
+                report += &format!("  {:<8} failed: {}\n", name, err);
+                continue;
+            }
+        };
+        let before: HashMap<u8, &Record> = match before {
+            Some(Ok(records)) => records.iter().map(|record| (record.part, record)).collect(),
+            _ => HashMap::new(),
+        };
+        for record in records {
+            let answer = answer(record);
+            let time = Duration::from_nanos(record.solve_ns);
+            let (answer, time) = match before.get(&record.part) {
+                Some(old) => {
+                    let old_answer = self::answer(old);
+                    let answer = if old_answer == answer {
+                        answer
+                    } else {
+                        format!("{} -> {}", old_answer, answer)
+                    };
+                    let old_time = Duration::from_nanos(old.solve_ns);
+                    (
+                        answer,
+                        format!("{:.2?} -> {:.2?}{}", old_time, time, change(old_time, time)),
+                    )
+                }
+                None => (answer, format!("{:.2?}", time)),
+            };
+            report += &format!(
+                "  {:<8} part {}  {:<24} {}\n",
+                name, record.part, answer, time
+            );
+        }
+    }
+
+    let tests = match &current.tests {
+        Ok(tests) => {
+            let was = match previous.map(|p| &p.tests) {
+                Some(Ok(before)) if before != tests => {
+                    format!(" (was {} passed, {} failed)", before.passed, before.failed)
+                }
+                _ => String::new(),
+            };
+            format!("{} passed, {} failed{}", tests.passed, tests.failed, was)
+        }
+        Err(err) => format!("failed: {}", err),
+    };
+    report + &format!("  {:<8} {}\n", "tests", tests)
+}
+
+fn answer(record: &Record) -> String {
+    match (&record.status, &record.answer) {
+        (Status::Unimplemented, _) => "not implemented".to_string(),
+        (Status::NoSolution, _) => "no solution".to_string(),
+        // a picture, too big for one line
+        (_, serde_json::Value::String(text)) if text.contains('\n') => {
+            format!("<{} lines, {}>", text.lines().count(), short_hash(text))
+        }
+        (_, serde_json::Value::String(text)) => text.clone(),
+        (_, answer) => answer.to_string(),
+    }
+}
+
+fn short_hash(text: &str) -> String {
+    crate::record::input_hash(text)[..8].to_string()
+}
+
+// e.g. " (+12%)", nothing when about the same
+fn change(before: Duration, now: Duration) -> String {
+    if before.is_zero() {
+        return String::new();
+    }
+    let percent = (now.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+    if percent.abs() < 1.0 {
+        String::new()
+    } else {
+        format!(" ({:+.0}%)", percent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(part: u8, answer: serde_json::Value, solve_ns: u64) -> Record {
+        Record {
+            day: 12,
+            part,
+            answer,
+            status: Status::Solved,
+            parse_ns: 0,
+            solve_ns,
+            peak_alloc_bytes: 0,
+            parse_peak_alloc_bytes: 0,
+            input_hash: String::new(),
+        }
+    }
+
+    fn watch() -> Watch {
+        let root = PathBuf::from("/work");
+        Watch {
+            year: 2022,
+            day: 12,
+            package: "day12".to_string(),
+            crate_dir: root.join("day12"),
+            inputs_dir: root.join("inputs"),
+            input: root.join("inputs/day12.txt"),
+            example: root.join("inputs/examples/day12.txt"),
+            root,
+        }
+    }
+
+    #[test]
+    fn test_is_watched() {
+        let watch = watch();
+        assert!(watch.is_watched(Path::new("/work/day12/src/lib.rs")));
+        assert!(watch.is_watched(Path::new("/work/day12/src/generate.rs")));
+        assert!(watch.is_watched(Path::new("/work/day12/Cargo.toml")));
+        assert!(watch.is_watched(Path::new("/work/inputs/day12.txt")));
+        assert!(watch.is_watched(Path::new("/work/inputs/examples/day12.txt")));
+        assert!(!watch.is_watched(Path::new("/work/inputs/day11.txt")));
+        assert!(!watch.is_watched(Path::new("/work/inputs/examples/day11.txt")));
+        assert!(!watch.is_watched(Path::new("/work/day12/target/debug/day12")));
+        assert!(!watch.is_watched(Path::new("/work/day12/src/.lib.rs.swp")));
+        assert!(!watch.is_watched(Path::new("/work/day12/src/sed6nLtQK")));
+        assert!(!watch.is_watched(Path::new("/work/day1/src/lib.rs")));
+    }
+
+    #[test]
+    fn test_is_change() {
+        use notify::event::{CreateKind, ModifyKind, RemoveKind};
+        assert!(is_change(&EventKind::Modify(ModifyKind::Any)));
+        assert!(is_change(&EventKind::Create(CreateKind::File)));
+        assert!(is_change(&EventKind::Remove(RemoveKind::File)));
+        assert!(is_change(&EventKind::Access(AccessKind::Close(
+            AccessMode::Write
+        ))));
+        assert!(!is_change(&EventKind::Access(AccessKind::Open(
+            AccessMode::Any
+        ))));
+        assert!(!is_change(&EventKind::Access(AccessKind::Close(
+            AccessMode::Read
+        ))));
+    }
+
+    #[test]
+    fn test_find_package() {
+        let metadata = serde_json::json!({
+            "packages": [
+                {
+                    "name": "aoc",
+                    "manifest_path": "/work/aoc/Cargo.toml",
+                    "targets": [{"name": "aoc", "kind": ["lib"]}, {"name": "aoc", "kind": ["bin"]}]
+                },
+                {
+                    "name": "day13",
+                    "manifest_path": "/work/day13_timvisee/Cargo.toml",
+                    "targets": [{"name": "day13", "kind": ["lib"]}]
+                }
+            ]
+        });
+        assert_eq!(
+            find_package(&metadata, "day13"),
+            Some((
+                "day13".to_string(),
+                PathBuf::from("/work/day13_timvisee/Cargo.toml")
+            ))
+        );
+        assert_eq!(find_package(&metadata, "day14"), None);
+    }
+
+    #[test]
+    fn test_error_line() {
+        let runner = "Error: \n   0: \x1b[91mcould not read input day12.txt\x1b[0m\n   1: \x1b[91mNo such file\x1b[0m\n\nLocation:\n   aoc/src/main.rs:193\n";
+        assert_eq!(
+            error_line(runner.as_bytes()),
+            "could not read input day12.txt"
+        );
+        let compiler = "warning: unused variable: `x`\n --> day12/src/lib.rs:3:9\nerror[E0308]: mismatched types\n --> day12/src/lib.rs:9:5\n";
+        assert_eq!(
+            error_line(compiler.as_bytes()),
+            "error[E0308]: mismatched types"
+        );
+        assert_eq!(error_line(b"\n\n"), "failed");
+    }
+
+    #[test]
+    fn test_tests_parse() {
+        let stdout = "\nrunning 3 tests\n...\ntest result: ok. 3 passed; 0 failed; 0 ignored; 0 measured; 2 filtered out; finished in 0.01s\n\nrunning 2 tests\n.F\ntest result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s\n";
+        assert_eq!(
+            Tests::parse(stdout),
+            Some(Tests {
+                passed: 4,
+                failed: 1
+            })
+        );
+        assert_eq!(Tests::parse("error: could not compile `day12`"), None);
+    }
+
+    #[test]
+    fn test_report() {
+        let first = Snapshot {
+            input: Ok(vec![
+                record(1, 31.into(), 2_000_000),
+                record(2, 29.into(), 1_000_000),
+            ]),
+            example: Err("could not read input".to_string()),
+            tests: Ok(Tests {
+                passed: 3,
+                failed: 0,
+            }),
+        };
+        assert_eq!(
+            report(None, &first),
+            "  input    part 1  31                       2.00ms\n\
+             \x20 input    part 2  29                       1.00ms\n\
+             \x20 example  failed: could not read input\n\
+             \x20 tests    3 passed, 0 failed\n"
+        );
+
+        let second = Snapshot {
+            input: Ok(vec![
+                record(1, 30.into(), 1_500_000),
+                record(2, 29.into(), 1_000_000),
+            ]),
+            example: Ok(vec![record(1, "#.\n.#".into(), 1_000)]),
+            tests: Ok(Tests {
+                passed: 2,
+                failed: 1,
+            }),
+        };
+        assert_eq!(
+            report(Some(&first), &second),
+            "  input    part 1  31 -> 30                 2.00ms -> 1.50ms (-25%)\n\
+             \x20 input    part 2  29                       1.00ms -> 1.00ms\n\
+             \x20 example  part 1  <2 lines, d705faa4>      1.00µs\n\
+             \x20 tests    2 passed, 1 failed (was 3 passed, 0 failed)\n"
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! Runs the fetch and submit flows over real HTTP against a stub of
+//! adventofcode.com listening on localhost.
+
+use std::{
+    env, fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use aoc::client::{self, Client, Fetched, GuessLog, Submission, UreqHttp, Verdict};
+use aoc_common::{input::Inputs, Part};
+
+#[derive(Debug, Clone)]
+struct Request {
+    method: String,
+    path: String,
+    cookie: String,
+    body: String,
+}
+
+/// Serves the canned `pages` in order, one per connection, and records what
+/// was asked.
+fn stub_server(pages: Vec<(u16, &'static str)>) -> (String, Arc<Mutex<Vec<Request>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&requests);
+    thread::spawn(move || {
+        for ((status, page), stream) in pages.into_iter().zip(listener.incoming()) {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut parts = request_line.split_whitespace();
+            let method = parts.next().unwrap().to_string();
+            let path = parts.next().unwrap().to_string();
+
+            let mut cookie = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                let header = header.trim_end();
+                if header.is_empty() {
+                    break;
+                }
+                let (name, value) = header.split_once(": ").unwrap();
+                match name.to_ascii_lowercase().as_str() {
+                    "cookie" => cookie = value.to_string(),
+                    "content-length" => content_length = value.parse().unwrap(),
+                    _ => {}
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+
+            recorded.lock().unwrap().push(Request {
+                method,
+                path,
+                cookie,
+                body: String::from_utf8(body).unwrap(),
+            });
+            write!(
+                stream,
+                "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                page.len(),
+                page
+            )
+            .unwrap();
+        }
+    });
+    (base_url, requests)
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-client-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn fetch_downloads_then_uses_cache() {
+    let (base_url, requests) = stub_server(vec![(200, "498,4 -> 498,6 -> 496,6\n")]);
+    let client = Client::new(UreqHttp::new(), "cafe").with_base_url(base_url);
+    let dir = temp_dir("fetch");
+    let inputs = Inputs::new(&dir);
+
+    let fetched = client::fetch(&client, &inputs, 14, false).unwrap();
+    assert_eq!(fetched, Fetched::Downloaded(dir.join("day14.txt")));
+    assert_eq!(
+        fs::read_to_string(dir.join("day14.txt")).unwrap(),
+        "498,4 -> 498,6 -> 496,6\n"
+    );
+
+    // the second fetch never reaches the server
+    let fetched = client::fetch(&client, &inputs, 14, false).unwrap();
+    assert_eq!(fetched, Fetched::Cached(dir.join("day14.txt")));
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2022/day/14/input");
+    assert_eq!(requests[0].cookie, "session=cafe");
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn fetch_another_year() {
+    let (base_url, requests) = stub_server(vec![(200, "1abc2\n")]);
+    let client = Client::new(UreqHttp::new(), "cafe")
+        .with_base_url(base_url)
+        .with_year(2023);
+    let dir = temp_dir("fetch-year");
+    let inputs = Inputs::new(&dir).year(2023);
+
+    let fetched = client::fetch(&client, &inputs, 1, false).unwrap();
+    assert_eq!(fetched, Fetched::Downloaded(dir.join("2023/day01.txt")));
+    assert_eq!(requests.lock().unwrap()[0].path, "/2023/day/1/input");
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn fetch_reports_http_errors() {
+    let (base_url, _) = stub_server(vec![(
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+    )]);
+    let client = Client::new(UreqHttp::new(), "expired").with_base_url(base_url);
+    let dir = temp_dir("fetch-error");
+
+    let err = client::fetch(&client, &Inputs::new(&dir), 3, false).unwrap_err();
+    assert!(err.to_string().contains("HTTP 400"));
+    assert!(!dir.join("day03.txt").exists());
+}
+
+#[test]
+fn submit_never_repeats_a_wrong_answer() {
+    let (base_url, requests) = stub_server(vec![
+        (
+            200,
+            "<main><article><p>That's not the right answer; your answer is too high.</p></article></main>",
+        ),
+        (
+            200,
+            "<main><article><p>You gave an answer too recently. You have 41s left to wait.</p></article></main>",
+        ),
+        (
+            200,
+            "<main><article><p>That's the right answer! You are one gold star closer.</p></article></main>",
+        ),
+    ]);
+    let client = Client::new(UreqHttp::new(), "cafe").with_base_url(base_url);
+    let dir = temp_dir("submit");
+    let log_path = dir.join("submissions.tsv");
+    let mut log = GuessLog::load(&log_path).unwrap();
+
+    let sent = client::submit(&client, &mut log, 11, Part::Two, "18085004900").unwrap();
+    assert_eq!(sent, Submission::Sent(Verdict::TooHigh));
+
+    // the same answer, and anything above it, is refused locally
+    for answer in ["18085004900", "18085005000"] {
+        let sent = client::submit(&client, &mut log, 11, Part::Two, answer).unwrap();
+        assert!(matches!(sent, Submission::Skipped(_)));
+    }
+
+    // rate limiting is not a verdict on the answer, so it is not logged
+    let sent = client::submit(&client, &mut log, 11, Part::Two, "18085004878").unwrap();
+    assert_eq!(
+        sent,
+        Submission::Sent(Verdict::Wait(std::time::Duration::from_secs(41)))
+    );
+    let sent = client::submit(&client, &mut log, 11, Part::Two, "18085004878").unwrap();
+    assert_eq!(sent, Submission::Sent(Verdict::Correct));
+
+    // the log survives a restart
+    let log = GuessLog::load(&log_path).unwrap();
+    assert_eq!(log.guesses().len(), 2);
+    assert!(log.rule_out(11, Part::Two, "18085004900").is_some());
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2022/day/11/answer");
+    assert_eq!(requests[0].body, "level=2&answer=18085004900");
+    fs::remove_dir_all(&dir).unwrap();
+}
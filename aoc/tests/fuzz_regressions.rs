@@ -0,0 +1,39 @@
+//! Every input the fuzz targets in `fuzz/` once crashed on, checked in under
+//! `fuzz/artifacts/<target>/`, must now parse or fail without panicking.
+
+use std::{fs, path::Path};
+
+use aoc_common::Solution;
+
+// what a fuzz target does with its input
+type Target = fn(&str);
+
+const TARGETS: &[(&str, Target)] = &[
+    ("day4_parse", |input| drop(day4::Day4::parse(input))),
+    ("day5_move", |input| drop(input.parse::<day5::Move>())),
+    ("day9_command", |input| drop(day9::parse_command(input))),
+    ("day11_monkey", |input| drop(day11::parse_monkey(input))),
+    ("day13_item", |input| drop(day13::item(input.as_bytes()))),
+    ("day14_parse", |input| drop(day14::Day14::parse(input))),
+    ("day15_parse", |input| drop(day15::Day15::parse(input))),
+];
+
+#[test]
+fn fuzz_artifacts_no_longer_crash() {
+    let artifacts = Path::new(env!("CARGO_MANIFEST_DIR")).join("../fuzz/artifacts");
+    let mut checked = 0;
+    for (target, run) in TARGETS {
+        let Ok(entries) = fs::read_dir(artifacts.join(target)) else {
+            continue;
+        };
+        for entry in entries {
+            let path = entry.unwrap().path();
+            // the targets skip input that is not UTF-8 too
+            if let Ok(input) = String::from_utf8(fs::read(&path).unwrap()) {
+                run(&input);
+                checked += 1;
+            }
+        }
+    }
+    assert!(checked > 0, "no artifacts found in {}", artifacts.display());
+}
@@ -0,0 +1,36 @@
+//! Every day's answers on the checked-in inputs must still match
+//! `inputs/answers.tsv`.
+
+use std::path::Path;
+
+use aoc::{
+    days::DAYS,
+    verify::{self, KnownAnswers, Outcome, ANSWERS_FILE},
+};
+use aoc_common::input::Inputs;
+
+#[test]
+fn recorded_answers_still_match() {
+    let all_inputs = Inputs::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs"));
+
+    let mut passed = 0;
+    let mut failures = Vec::new();
+    for day in DAYS {
+        // each year keeps its inputs and answers apart
+        let inputs = all_inputs.year(day.year);
+        let answers = KnownAnswers::load(inputs.dir().join(ANSWERS_FILE)).unwrap();
+        for check in verify::verify(day, &inputs, &answers).unwrap() {
+            match check.outcome {
+                Outcome::Pass => passed += 1,
+                Outcome::Fail { expected, actual } => failures.push(format!(
+                    "day {} part {} {}: expected {:?}, got {:?}",
+                    check.day, check.part, check.fixture, expected, actual
+                )),
+                Outcome::Missing => {}
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+    assert!(passed > 0, "no answers recorded in {}", ANSWERS_FILE);
+}
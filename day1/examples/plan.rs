@@ -0,0 +1,59 @@
+//! Evens out the packs of day 1 over `k` elves, then prints what each of
+//! them carries, how far the plan may be from the best one and the moves:
+//!
+//! ```text
+//! cargo run -p day1 --example plan -- <k> [<input file, or - for stdin>]
+//! ```
+
+use std::{env, path::PathBuf, process};
+
+use aoc_common::{
+    input::{InputSource, Inputs},
+    Error, Result, Solution,
+};
+use day1::{plan, Day1};
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
+    let mut args = env::args_os().skip(1);
+    let elves: usize = args
+        .next()
+        .and_then(|arg| arg.to_str()?.parse().ok())
+        .ok_or_else(|| Error::InvalidInput("expected the number of elves first".to_string()))?;
+    let input = args.next().map(PathBuf::from);
+    let input = Inputs::from_env()
+        .year(Day1::YEAR)
+        .load(Day1::DAY, &InputSource::from_arg(input.as_deref()))?;
+    let inventory = Day1::parse(&input)?;
+
+    let plan = plan::plan(&inventory, elves)
+        .ok_or_else(|| Error::InvalidInput("there is no plan for 0 elves".to_string()))?;
+    println!("plan:");
+    for (elf, load) in plan.loads.iter().enumerate() {
+        println!("{:>5}  {}", elf + 1, load);
+    }
+    println!("\nheaviest pack: {}", plan.max_load());
+    if plan.is_optimal() {
+        println!("gap: 0%, optimal");
+    } else {
+        println!(
+            "gap: at most {:.2}% over the best, which is at least {}",
+            plan.gap() * 100.0,
+            plan.lower_bound
+        );
+    }
+    println!("\nmoves:");
+    for step in &plan.moves {
+        println!("  {}", step);
+    }
+    if plan.moves.is_empty() {
+        println!("  none");
+    }
+    Ok(())
+}
@@ -0,0 +1,67 @@
+//! Random calorie lists, with both answers.
+
+use aoc_common::generate::{Generated, Rng};
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// At least three, for part two.
+    pub elves: usize,
+    pub max_items: usize,
+    pub max_calories: u32,
+}
+
+/// About the size of a real input.
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            elves: 250,
+            max_items: 15,
+            max_calories: 60_000,
+        }
+    }
+}
+
+pub fn generate(seed: u64, params: &Params) -> Generated {
+    assert!(params.elves >= 3, "part two needs three elves");
+    let mut rng = Rng::new(seed);
+    let mut totals = Vec::with_capacity(params.elves);
+    let elves: Vec<String> = (0..params.elves)
+        .map(|_| {
+            let items: Vec<i64> = (0..rng.between(1..=params.max_items as i64))
+                .map(|_| rng.between(1..=i64::from(params.max_calories)))
+                .collect();
+            totals.push(items.iter().sum::<i64>());
+            items
+                .iter()
+                .map(|calories| calories.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect();
+
+    totals.sort_unstable_by(|a, b| b.cmp(a));
+    Generated::new(elves.join("\n\n")).with_answers(totals[0], totals[..3].iter().sum::<i64>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day1;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            generate(1, &Params::default()),
+            generate(1, &Params::default())
+        );
+        for seed in 0..20 {
+            generate(seed, &Params::default()).assert_solved_by::<Day1>();
+        }
+        let small = Params {
+            elves: 3,
+            max_items: 1,
+            max_calories: 1,
+        };
+        assert_eq!(generate(0, &small).input, "1\n\n1\n\n1");
+    }
+}
@@ -0,0 +1,439 @@
+pub mod generate;
+pub mod plan;
+
+use aoc_common::{Answer, Error, Result, Solution};
+use aoc_macros::aoc;
+use std::{cmp::Reverse, collections::BinaryHeap, fmt, str::FromStr};
+
+pub struct Day1;
+
+#[aoc(day = 1)]
+impl Solution for Day1 {
+    type Input = Inventory;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(inventory: &Self::Input) -> Answer {
+        inventory.summary().part_one().into()
+    }
+
+    fn part_two(inventory: &Self::Input) -> Answer {
+        inventory.summary().part_two().into()
+    }
+
+    fn breakdown(inventory: &Self::Input) -> Option<String> {
+        Some(inventory.render_breakdown())
+    }
+}
+
+/// The calories of the food each elf carries, elves in the order they are
+/// listed.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Inventory {
+    elves: Vec<Elf>,
+    skipped: usize,
+    // made once the elves are all in, for both parts to share
+    summary: Summary,
+}
+
+/// The calories of every food item one elf carries.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Elf {
+    pub items: Vec<u64>,
+}
+
+/// What to do with a line that is not a number of calories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    /// Fail, pointing at the line.
+    #[default]
+    Strict,
+    /// Skip it, counting it in [`Inventory::skipped`].
+    Lenient,
+}
+
+/// The items of one elf at a glance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub count: usize,
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub median: f64,
+}
+
+/// Both parts from one pass over the inventory: the elves carrying the most
+/// calories, most first, as (elf index, total).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Summary {
+    pub top_three: Vec<(usize, u64)>,
+}
+
+/// Parses strictly, see [`Inventory::parse`].
+impl FromStr for Inventory {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Inventory::parse(input, Mode::Strict)
+    }
+}
+
+impl Inventory {
+    pub fn new(elves: Vec<Elf>) -> Self {
+        let mut inventory = Inventory {
+            elves,
+            ..Inventory::default()
+        };
+        inventory.summary = inventory.summarize();
+        inventory
+    }
+
+    /// Elves carry one item per line and are separated by blank lines, with
+    /// `\n` or `\r\n` line endings.
+    pub fn parse(input: &str, mode: Mode) -> Result<Self> {
+        let mut inventory = Inventory::default();
+        // whether the last line was part of an elf, so that the next blank
+        // line ends it
+        let mut in_elf = false;
+        for line in input.lines() {
+            if line.is_empty() {
+                in_elf = false;
+                continue;
+            }
+            if !in_elf {
+                inventory.elves.push(Elf::default());
+                in_elf = true;
+            }
+            match (line.parse(), mode) {
+                (Ok(calories), _) => inventory.elves.last_mut().unwrap().items.push(calories),
+                (Err(_), Mode::Lenient) => inventory.skipped += 1,
+                (Err(_), Mode::Strict) => {
+                    return Err(Error::at(
+                        input,
+                        line,
+                        format!("{:?} is not a number of calories", line),
+                    ))
+                }
+            }
+        }
+        inventory.summary = inventory.summarize();
+        Ok(inventory)
+    }
+
+    pub fn elves(&self) -> &[Elf] {
+        &self.elves
+    }
+
+    /// How many lines a lenient parse skipped.
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// The calories each elf carries, in all.
+    pub fn totals(&self) -> impl Iterator<Item = u64> + '_ {
+        self.elves.iter().map(Elf::total)
+    }
+
+    /// The `n` elves carrying the most calories, most first, as (index into
+    /// [`elves`](Self::elves), total). Of elves carrying as much, the one
+    /// listed first comes first.
+    pub fn top_n(&self, n: usize) -> Vec<(usize, u64)> {
+        let ranked = self
+            .totals()
+            .enumerate()
+            .map(|(elf, total)| (total, Reverse(elf)));
+        top_n(ranked, n)
+            .into_iter()
+            .map(|(total, Reverse(elf))| (elf, total))
+            .collect()
+    }
+
+    pub fn summary(&self) -> &Summary {
+        &self.summary
+    }
+
+    fn summarize(&self) -> Summary {
+        Summary {
+            top_three: self.top_n(3),
+        }
+    }
+
+    /// The [`Stats`] of every elf, `None` for an elf carrying nothing.
+    pub fn stats(&self) -> Vec<Option<Stats>> {
+        self.elves.iter().map(Elf::stats).collect()
+    }
+
+    /// The [`Summary`] followed by the [`Stats`] of every elf, numbered from
+    /// 1 as in the puzzle.
+    pub fn render_breakdown(&self) -> String {
+        let mut text = format!(
+            "{}\n\n  elf  items      total        min        max       mean     median\n",
+            self.summary
+        );
+        for (index, (elf, stats)) in self.elves.iter().zip(self.stats()).enumerate() {
+            text.push_str(&format!("{:>5}", index + 1));
+            match stats {
+                Some(stats) => text.push_str(&format!(
+                    "  {:>5}  {:>9}  {:>9}  {:>9}  {:>9.1}  {:>9.1}\n",
+                    stats.count,
+                    elf.total(),
+                    stats.min,
+                    stats.max,
+                    stats.mean,
+                    stats.median
+                )),
+                None => text.push_str("  carries nothing\n"),
+            }
+        }
+        text
+    }
+}
+
+impl Elf {
+    pub fn total(&self) -> u64 {
+        self.items.iter().sum()
+    }
+
+    pub fn stats(&self) -> Option<Stats> {
+        let mut items = self.items.clone();
+        items.sort_unstable();
+        let (&min, &max) = (items.first()?, items.last()?);
+        let count = items.len();
+        let middle = count / 2;
+        let median = if count % 2 == 1 {
+            items[middle] as f64
+        } else {
+            (items[middle - 1] as f64 + items[middle] as f64) / 2.0
+        };
+        Some(Stats {
+            count,
+            min,
+            max,
+            mean: items.iter().map(|&item| item as f64).sum::<f64>() / count as f64,
+            median,
+        })
+    }
+}
+
+impl Summary {
+    /// The most calories any elf carries.
+    pub fn part_one(&self) -> Option<u64> {
+        self.top_three.first().map(|&(_, total)| total)
+    }
+
+    /// The calories the three elves carrying the most carry together.
+    pub fn part_two(&self) -> Option<u64> {
+        (self.top_three.len() == 3).then(|| self.top_three.iter().map(|&(_, total)| total).sum())
+    }
+}
+
+/// Elves are numbered from 1, as in the puzzle.
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(&(elf, total)) = self.top_three.first() else {
+            return write!(f, "no elves");
+        };
+        write!(f, "elf {} carries the most, {} calories", elf + 1, total)?;
+        if let Some(together) = self.part_two() {
+            let elves: Vec<String> = self
+                .top_three
+                .iter()
+                .map(|(elf, _)| (elf + 1).to_string())
+                .collect();
+            write!(
+                f,
+                "; elves {} carry {} together",
+                elves.join(", "),
+                together
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The `n` largest of `items`, largest first, keeping no more than `n` of them
+/// at a time.
+pub fn top_n<T: Ord>(items: impl IntoIterator<Item = T>, n: usize) -> Vec<T> {
+    if n == 0 {
+        return Vec::new();
+    }
+    // the smallest of the largest so far on top, to make way for larger ones
+    let mut heap = BinaryHeap::with_capacity(n + 1);
+    for item in items {
+        heap.push(Reverse(item));
+        if heap.len() > n {
+            heap.pop();
+        }
+    }
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(item)| item)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Inventory {
+        Day1::parse(include_str!("../../inputs/examples/day01.txt")).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let inventory = example();
+        assert_eq!(inventory.elves().len(), 5);
+        assert_eq!(inventory.elves()[0].items, [1000, 2000, 3000]);
+        assert_eq!(
+            inventory.totals().collect::<Vec<_>>(),
+            [6000, 4000, 11000, 24000, 10000]
+        );
+
+        assert_eq!("1\n2\n\n3\n".parse::<Inventory>().unwrap().elves().len(), 2);
+        assert_eq!("".parse::<Inventory>().unwrap(), Inventory::default());
+        let crlf = "1\r\n2\r\n\r\n3\r\n".parse::<Inventory>().unwrap();
+        assert_eq!(
+            crlf,
+            Inventory::new(vec![Elf { items: vec![1, 2] }, Elf { items: vec![3] }])
+        );
+        let blank_lines = "\n1\n\n\n\n2\n\n".parse::<Inventory>().unwrap();
+        assert_eq!(blank_lines.totals().collect::<Vec<_>>(), [1, 2]);
+
+        // too much for an i32, or a u32 once summed
+        let big = "3000000000\n3000000000".parse::<Inventory>().unwrap();
+        assert_eq!(big.summary().part_one(), Some(6_000_000_000));
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        let error = |input: &str| match input.parse::<Inventory>().unwrap_err() {
+            Error::Parse(err) => (err.line, err.text, err.message),
+            err => panic!("unexpected error: {}", err),
+        };
+        assert_eq!(
+            error("1000\n12a4\n\n3000"),
+            (
+                2,
+                "12a4".to_string(),
+                "\"12a4\" is not a number of calories".to_string()
+            )
+        );
+        assert_eq!(error("1000\n\n3000 \n").0, 3);
+        assert_eq!(error("1000\r\n\r\n \r\n").0, 3);
+        assert_eq!(error("-5").0, 1);
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        let input = "1000\n12a4\n\n3000 \nx\n\n5000";
+        let inventory = Inventory::parse(input, Mode::Lenient).unwrap();
+        assert_eq!(inventory.skipped(), 3);
+        assert_eq!(
+            inventory.elves(),
+            [
+                Elf { items: vec![1000] },
+                Elf { items: vec![] },
+                Elf { items: vec![5000] }
+            ]
+        );
+        assert_eq!(inventory.summary().part_one(), Some(5000));
+
+        let clean = Inventory::parse("1\n\n2", Mode::Lenient).unwrap();
+        assert_eq!(clean, "1\n\n2".parse().unwrap());
+        assert_eq!(clean.skipped(), 0);
+    }
+
+    #[test]
+    fn test_top_n() {
+        let inventory = example();
+        assert_eq!(inventory.top_n(3), [(3, 24000), (2, 11000), (4, 10000)]);
+        assert_eq!(inventory.top_n(1), [(3, 24000)]);
+        assert_eq!(inventory.top_n(0), []);
+        assert_eq!(inventory.top_n(9).len(), 5);
+
+        let tied = "5\n\n7\n\n5\n\n7".parse::<Inventory>().unwrap();
+        assert_eq!(tied.top_n(3), [(1, 7), (3, 7), (0, 5)]);
+
+        assert_eq!(top_n([3, 1, 4, 1, 5, 9, 2, 6], 3), [9, 6, 5]);
+        assert_eq!(top_n(Vec::<u8>::new(), 3), []);
+    }
+
+    #[test]
+    fn test_summary() {
+        let summary = example().summary().clone();
+        assert_eq!(summary.part_one(), Some(24000));
+        assert_eq!(summary.part_two(), Some(45000));
+        assert_eq!(
+            summary.to_string(),
+            "elf 4 carries the most, 24000 calories; elves 4, 3, 5 carry 45000 together"
+        );
+
+        let two = "1\n\n2".parse::<Inventory>().unwrap().summary().clone();
+        assert_eq!(two.part_one(), Some(2));
+        assert_eq!(two.part_two(), None);
+        assert_eq!(two.to_string(), "elf 2 carries the most, 2 calories");
+        assert_eq!(Inventory::default().summary().to_string(), "no elves");
+        assert_eq!(
+            Inventory::new(vec![Elf { items: vec![1] }])
+                .summary()
+                .part_one(),
+            Some(1)
+        );
+        assert_eq!(
+            Day1::part_two(&"1\n\n2".parse().unwrap()),
+            Answer::NoSolution
+        );
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = example().stats();
+        assert_eq!(
+            stats[0],
+            Some(Stats {
+                count: 3,
+                min: 1000,
+                max: 3000,
+                mean: 2000.0,
+                median: 2000.0,
+            })
+        );
+        assert_eq!(
+            stats[2],
+            Some(Stats {
+                count: 2,
+                min: 5000,
+                max: 6000,
+                mean: 5500.0,
+                median: 5500.0,
+            })
+        );
+        let uneven = Elf {
+            items: vec![9, 1, 2, 100],
+        };
+        assert_eq!(uneven.stats().unwrap().median, 5.5);
+        assert_eq!(uneven.stats().unwrap().mean, 28.0);
+        assert_eq!(Elf::default().stats(), None);
+    }
+
+    #[test]
+    fn test_breakdown() {
+        assert_eq!(
+            Day1::breakdown(&example()).unwrap(),
+            "elf 4 carries the most, 24000 calories; elves 4, 3, 5 carry 45000 together\n\n\
+             \x20 elf  items      total        min        max       mean     median\n\
+             \x20   1      3       6000       1000       3000     2000.0     2000.0\n\
+             \x20   2      1       4000       4000       4000     4000.0     4000.0\n\
+             \x20   3      2      11000       5000       6000     5500.0     5500.0\n\
+             \x20   4      3      24000       7000       9000     8000.0     8000.0\n\
+             \x20   5      1      10000      10000      10000    10000.0    10000.0\n"
+        );
+        let lenient = Inventory::parse("x\n\n1", Mode::Lenient).unwrap();
+        assert_eq!(
+            lenient.render_breakdown().lines().nth(3).unwrap(),
+            "    1  carries nothing"
+        );
+    }
+}
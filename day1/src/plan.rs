@@ -0,0 +1,510 @@
+//! Evening out the packs: moving food items between elves so that the most
+//! any of them carries is as little as it can be.
+//!
+//! It is the multiprocessor scheduling problem, so [`exact`] searches all
+//! assignments and is only fit for a few items; [`greedy`] gives each item,
+//! largest first, to the elf carrying the least (LPT) and says how far from
+//! the best it may be.
+
+use std::{cmp::Reverse, collections::HashMap, fmt};
+
+use crate::{Elf, Inventory};
+
+/// Most items [`plan`] still solves exactly.
+pub const EXACT_ITEMS: usize = 16;
+
+/// How the items end up spread over the elves, and the moves that get them
+/// there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    /// What each elf carries afterwards.
+    pub loads: Vec<u64>,
+    /// The least the heaviest pack can weigh, as far as is known; that of
+    /// the plan itself once it is known to be optimal.
+    pub lower_bound: u64,
+    pub moves: Vec<Move>,
+}
+
+/// Item `item` of elf `from`, counted in that elf's items as parsed, goes to
+/// elf `to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub from: usize,
+    pub item: usize,
+    pub to: usize,
+    pub calories: u64,
+}
+
+impl Plan {
+    pub fn max_load(&self) -> u64 {
+        self.loads.iter().copied().max().unwrap_or(0)
+    }
+
+    pub fn is_optimal(&self) -> bool {
+        self.max_load() == self.lower_bound
+    }
+
+    /// How much more the heaviest pack may be than in the best plan, as a
+    /// fraction of the best: 0 when optimal.
+    pub fn gap(&self) -> f64 {
+        match self.lower_bound {
+            0 => 0.0,
+            bound => (self.max_load() - bound) as f64 / bound as f64,
+        }
+    }
+
+    /// The first `loads.len()` elves of `inventory` once the moves are made.
+    pub fn apply(&self, inventory: &Inventory) -> Inventory {
+        let mut elves = vec![Elf::default(); self.loads.len()];
+        for (from, elf) in inventory.elves().iter().enumerate() {
+            for (item, &calories) in elf.items.iter().enumerate() {
+                let to = self
+                    .moves
+                    .iter()
+                    .find(|step| step.from == from && step.item == item)
+                    .map_or(from, |step| step.to);
+                elves[to].items.push(calories);
+            }
+        }
+        Inventory::new(elves)
+    }
+}
+
+/// Elves and items are numbered from 1, as in the puzzle.
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "elf {} gives item {} ({} calories) to elf {}",
+            self.from + 1,
+            self.item + 1,
+            self.calories,
+            self.to + 1
+        )
+    }
+}
+
+/// Spreads the items of `inventory` over its first `elves` elves, which are
+/// added empty if there are not that many: exactly when there are at most
+/// [`EXACT_ITEMS`] items, greedily otherwise. `None` without any elf.
+pub fn plan(inventory: &Inventory, elves: usize) -> Option<Plan> {
+    if items(inventory).len() <= EXACT_ITEMS {
+        exact(inventory, elves)
+    } else {
+        greedy(inventory, elves)
+    }
+}
+
+/// The best plan, by branch and bound over which elf gets each item, so
+/// always [optimal](Plan::is_optimal). Exponential in the number of items in
+/// the worst case.
+pub fn exact(inventory: &Inventory, elves: usize) -> Option<Plan> {
+    if elves == 0 {
+        return None;
+    }
+    let items = items(inventory);
+    let calories: Vec<u64> = items.iter().map(|item| item.calories).collect();
+    let lower_bound = lower_bound(&calories, elves);
+
+    // the greedy plan to beat, then every assignment that might
+    let best = lpt(&calories, elves);
+    let mut search = Search {
+        calories: &calories,
+        loads: vec![0; elves],
+        assignment: vec![0; items.len()],
+        remaining: calories.iter().sum(),
+        best_max: max_load(&best, &calories, elves),
+        best,
+        lower_bound,
+    };
+    if search.best_max > lower_bound {
+        search.assign(0);
+    }
+    Some(finish(&items, &search.best, elves, search.best_max))
+}
+
+/// The longest-processing-time-first plan: each item, largest first, goes
+/// to the elf carrying the least. Its heaviest pack is at most 4/3 of the
+/// best one's; [`Plan::gap`] tells how close it got.
+pub fn greedy(inventory: &Inventory, elves: usize) -> Option<Plan> {
+    if elves == 0 {
+        return None;
+    }
+    let items = items(inventory);
+    let calories: Vec<u64> = items.iter().map(|item| item.calories).collect();
+    let assignment = lpt(&calories, elves);
+    Some(finish(
+        &items,
+        &assignment,
+        elves,
+        lower_bound(&calories, elves),
+    ))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Item {
+    elf: usize,
+    index: usize,
+    calories: u64,
+}
+
+// Every item, largest first, which the bounds and both solvers want
+fn items(inventory: &Inventory) -> Vec<Item> {
+    let mut items: Vec<Item> = inventory
+        .elves()
+        .iter()
+        .enumerate()
+        .flat_map(|(elf, items)| {
+            items
+                .items
+                .iter()
+                .enumerate()
+                .map(move |(index, &calories)| Item {
+                    elf,
+                    index,
+                    calories,
+                })
+        })
+        .collect();
+    items.sort_by_key(|item| Reverse(item.calories));
+    items
+}
+
+// No pack can be lighter than an even share, nor than the largest item, nor
+// than two of the `elves + 1` largest, which cannot all go to different packs
+fn lower_bound(calories: &[u64], elves: usize) -> u64 {
+    let total: u64 = calories.iter().sum();
+    let largest = calories.first().copied().unwrap_or(0);
+    let paired = match calories.get(elves) {
+        Some(&smallest) => calories[elves - 1] + smallest,
+        None => 0,
+    };
+    total.div_ceil(elves as u64).max(largest).max(paired)
+}
+
+// The pack each item goes in
+fn lpt(calories: &[u64], elves: usize) -> Vec<usize> {
+    let mut loads = vec![0; elves];
+    calories
+        .iter()
+        .map(|&item| {
+            let (pack, _) = loads
+                .iter()
+                .enumerate()
+                .min_by_key(|&(_, load)| load)
+                .unwrap();
+            loads[pack] += item;
+            pack
+        })
+        .collect()
+}
+
+fn max_load(assignment: &[usize], calories: &[u64], elves: usize) -> u64 {
+    let mut loads = vec![0; elves];
+    for (&pack, &item) in assignment.iter().zip(calories) {
+        loads[pack] += item;
+    }
+    loads.into_iter().max().unwrap_or(0)
+}
+
+struct Search<'a> {
+    calories: &'a [u64],
+    loads: Vec<u64>,
+    assignment: Vec<usize>,
+    // calories of the items not assigned yet
+    remaining: u64,
+    best: Vec<usize>,
+    best_max: u64,
+    lower_bound: u64,
+}
+
+impl Search<'_> {
+    // Puts item `next` and the ones after it in every pack worth trying;
+    // true once a plan meeting the lower bound is found
+    fn assign(&mut self, next: usize) -> bool {
+        if next == self.calories.len() {
+            let max = self.loads.iter().copied().max().unwrap_or(0);
+            if max < self.best_max {
+                self.best_max = max;
+                self.best.clone_from(&self.assignment);
+            }
+            return self.best_max == self.lower_bound;
+        }
+        let item = self.calories[next];
+        self.remaining -= item;
+        // lightest first, and only one of the packs carrying as much since
+        // they are interchangeable, as are all the empty ones
+        let mut packs: Vec<usize> = (0..self.loads.len()).collect();
+        packs.sort_by_key(|&pack| self.loads[pack]);
+        packs.dedup_by_key(|pack| self.loads[*pack]);
+        for pack in packs {
+            let load = self.loads[pack];
+            if load + item >= self.best_max {
+                break;
+            }
+            self.loads[pack] += item;
+            self.assignment[next] = pack;
+            // what is left needs room under the best, spread as evenly as can be
+            let room: u64 = self
+                .loads
+                .iter()
+                .map(|&load| (self.best_max - 1).saturating_sub(load))
+                .sum();
+            let found = room >= self.remaining && self.assign(next + 1);
+            self.loads[pack] -= item;
+            if found {
+                self.remaining += item;
+                return true;
+            }
+        }
+        self.remaining += item;
+        false
+    }
+}
+
+// Turns packs into elves, keeping as many items where they are as it can,
+// and lists the moves
+fn finish(items: &[Item], assignment: &[usize], elves: usize, lower_bound: u64) -> Plan {
+    // items each pack would keep in place if given to each elf, most first;
+    // only pairs sharing an item are counted, so there are at most as many
+    // as items rather than elves squared
+    let mut kept: HashMap<(usize, usize), usize> = HashMap::new();
+    for (item, &pack) in items.iter().zip(assignment) {
+        if item.elf < elves {
+            *kept.entry((pack, item.elf)).or_default() += 1;
+        }
+    }
+    let mut pairs: Vec<(usize, usize, usize)> = kept
+        .into_iter()
+        .map(|((pack, elf), count)| (count, pack, elf))
+        .collect();
+    pairs.sort_by_key(|&(count, pack, elf)| (Reverse(count), pack, elf));
+
+    let mut owner: Vec<Option<usize>> = vec![None; elves];
+    let mut taken = vec![false; elves];
+    for (_, pack, elf) in pairs {
+        if owner[pack].is_none() && !taken[elf] {
+            owner[pack] = Some(elf);
+            taken[elf] = true;
+        }
+    }
+    let mut free = (0..elves).filter(|&elf| !taken[elf]);
+    let owner: Vec<usize> = owner
+        .into_iter()
+        .map(|elf| elf.or_else(|| free.next()).unwrap())
+        .collect();
+
+    let mut loads = vec![0; elves];
+    let mut moves = Vec::new();
+    for (item, &pack) in items.iter().zip(assignment) {
+        let to = owner[pack];
+        loads[to] += item.calories;
+        if to != item.elf {
+            moves.push(Move {
+                from: item.elf,
+                item: item.index,
+                to,
+                calories: item.calories,
+            });
+        }
+    }
+    moves.sort_by_key(|step| (step.from, step.item));
+    Plan {
+        loads,
+        lower_bound,
+        moves,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generate, Day1};
+    use aoc_common::Solution;
+
+    fn inventory(elves: &[&[u64]]) -> Inventory {
+        Inventory::new(
+            elves
+                .iter()
+                .map(|items| Elf {
+                    items: items.to_vec(),
+                })
+                .collect(),
+        )
+    }
+
+    // the plan's moves lead to its loads, and no item is lost on the way
+    fn check(inventory: &Inventory, plan: &Plan) {
+        let after = plan.apply(inventory);
+        assert_eq!(after.totals().collect::<Vec<_>>(), plan.loads);
+        let mut before: Vec<u64> = inventory
+            .elves()
+            .iter()
+            .flat_map(|elf| elf.items.clone())
+            .collect();
+        let mut moved: Vec<u64> = after
+            .elves()
+            .iter()
+            .flat_map(|elf| elf.items.clone())
+            .collect();
+        before.sort_unstable();
+        moved.sort_unstable();
+        assert_eq!(before, moved);
+        assert!(plan.max_load() >= plan.lower_bound);
+    }
+
+    #[test]
+    fn test_example() {
+        let example = Day1::parse(include_str!("../../inputs/examples/day01.txt")).unwrap();
+        for plan in [exact(&example, 5).unwrap(), greedy(&example, 5).unwrap()] {
+            check(&example, &plan);
+            assert_eq!(plan.max_load(), 11000);
+            assert!(plan.is_optimal());
+            assert_eq!(plan.gap(), 0.0);
+        }
+
+        let plan = exact(&example, 2).unwrap();
+        check(&example, &plan);
+        assert_eq!(plan.loads.len(), 2);
+        assert_eq!(plan.max_load(), 28000);
+        assert!(plan.moves.iter().all(|step| step.to < 2));
+    }
+
+    #[test]
+    fn test_exact_beats_greedy() {
+        // LPT pairs the threes, leaving three twos for one elf
+        let packs = inventory(&[&[3, 3, 2, 2, 2]]);
+        let greedy = greedy(&packs, 2).unwrap();
+        check(&packs, &greedy);
+        assert_eq!((greedy.max_load(), greedy.lower_bound), (7, 6));
+        assert!((greedy.gap() - 1.0 / 6.0).abs() < 1e-9);
+
+        let exact = exact(&packs, 2).unwrap();
+        check(&packs, &exact);
+        assert_eq!(exact.max_load(), 6);
+        assert!(exact.is_optimal());
+        // the elf that had everything keeps the twos or the threes
+        assert!(exact.moves.len() == 2 || exact.moves.len() == 3);
+        assert_eq!(plan(&packs, 2), Some(exact));
+    }
+
+    #[test]
+    fn test_exact_without_meeting_the_bound() {
+        // an even share is 5, but two of the four items share a pack
+        let packs = inventory(&[&[4, 4], &[4, 3]]);
+        assert_eq!(greedy(&packs, 3).unwrap().lower_bound, 7);
+
+        // an even share is 10, but no split of these makes it
+        let odd = inventory(&[&[6, 5], &[3, 3, 3]]);
+        assert_eq!(greedy(&odd, 2).unwrap().lower_bound, 10);
+        let plan = exact(&odd, 2).unwrap();
+        check(&odd, &plan);
+        assert_eq!((plan.max_load(), plan.lower_bound), (11, 11));
+    }
+
+    #[test]
+    fn test_moves() {
+        // already even: nothing moves
+        let even = inventory(&[&[5], &[2, 3], &[4, 1]]);
+        let plan = exact(&even, 3).unwrap();
+        assert_eq!(plan.moves, []);
+        assert_eq!(plan.loads, [5, 5, 5]);
+
+        // a new elf takes some, and the last one gives away everything
+        let packs = inventory(&[&[4, 4], &[], &[1]]);
+        let plan = exact(&packs, 2).unwrap();
+        check(&packs, &plan);
+        assert_eq!(plan.loads, [5, 4]);
+        assert_eq!(
+            plan.moves,
+            [
+                Move {
+                    from: 0,
+                    item: 1,
+                    to: 1,
+                    calories: 4
+                },
+                Move {
+                    from: 2,
+                    item: 0,
+                    to: 0,
+                    calories: 1
+                }
+            ]
+        );
+        assert_eq!(
+            plan.moves[0].to_string(),
+            "elf 1 gives item 2 (4 calories) to elf 2"
+        );
+
+        let plan = greedy(&packs, 4).unwrap();
+        check(&packs, &plan);
+        assert_eq!(plan.loads.len(), 4);
+        assert_eq!(plan.max_load(), 4);
+    }
+
+    #[test]
+    fn test_many_elves() {
+        // far more elves than items: every item gets an elf of its own
+        let packs = inventory(&[&[5, 4, 3], &[2, 1]]);
+        let plan = greedy(&packs, 8000).unwrap();
+        check(&packs, &plan);
+        assert_eq!(plan.loads.len(), 8000);
+        assert_eq!(plan.max_load(), 5);
+        assert_eq!(plan.moves.len(), 3);
+    }
+
+    #[test]
+    fn test_no_elves() {
+        let packs = inventory(&[&[1]]);
+        assert_eq!(plan(&packs, 0), None);
+        assert_eq!(exact(&packs, 0), None);
+        assert_eq!(greedy(&packs, 0), None);
+
+        let nothing = exact(&Inventory::default(), 3).unwrap();
+        assert_eq!(nothing.loads, [0, 0, 0]);
+        assert!(nothing.is_optimal());
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..5 {
+            let small = generate::Params {
+                elves: 3,
+                max_items: 3,
+                max_calories: 100,
+            };
+            let packs = Day1::parse(&generate::generate(seed, &small).input).unwrap();
+            let exact = exact(&packs, 3).unwrap();
+            let greedy = greedy(&packs, 3).unwrap();
+            check(&packs, &exact);
+            check(&packs, &greedy);
+            assert!(exact.max_load() <= greedy.max_load());
+            // no assignment beats it
+            assert_eq!(exact.max_load(), brute_force(&packs, 3));
+
+            let large = Day1::parse(&generate::generate(seed, &Default::default()).input).unwrap();
+            let plan = plan(&large, large.elves().len()).unwrap();
+            check(&large, &plan);
+            assert!(plan.gap() < 1.0 / 3.0);
+        }
+    }
+
+    fn brute_force(inventory: &Inventory, elves: usize) -> u64 {
+        let calories: Vec<u64> = inventory
+            .elves()
+            .iter()
+            .flat_map(|elf| elf.items.clone())
+            .collect();
+        let mut best = u64::MAX;
+        for mut code in 0..elves.pow(calories.len() as u32) {
+            let mut loads = vec![0; elves];
+            for &item in &calories {
+                loads[code % elves] += item;
+                code /= elves;
+            }
+            best = best.min(loads.into_iter().max().unwrap());
+        }
+        best
+    }
+}
@@ -0,0 +1,90 @@
+//! Random CPU programs of exactly 240 cycles, one per pixel of the screen,
+//! with both answers.
+
+use aoc_common::generate::{Generated, Rng};
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// How often an instruction is `noop` rather than `addx`.
+    pub noops: f64,
+}
+
+/// About like a real input.
+impl Default for Params {
+    fn default() -> Self {
+        Params { noops: 0.4 }
+    }
+}
+
+const CYCLES: usize = 240;
+const WIDTH: usize = 40;
+
+pub fn generate(seed: u64, params: &Params) -> Generated {
+    let mut rng = Rng::new(seed);
+    let mut program = Vec::new();
+    // the value of X during each cycle
+    let mut x_during = Vec::with_capacity(CYCLES);
+    let mut x: i64 = 1;
+    while x_during.len() < CYCLES {
+        if x_during.len() + 1 == CYCLES || rng.chance(params.noops) {
+            program.push("noop".to_string());
+            x_during.push(x);
+        } else {
+            // keep the sprite near the screen
+            let value = loop {
+                let value = rng.between(-1..=WIDTH as i64) - x;
+                if value != 0 {
+                    break value;
+                }
+            };
+            program.push(format!("addx {}", value));
+            x_during.extend([x, x]);
+            x += value;
+        }
+    }
+
+    let part_one: i64 = (20..=220)
+        .step_by(40)
+        .map(|cycle| cycle as i64 * x_during[cycle - 1])
+        .sum();
+    let screen: Vec<String> = x_during
+        .chunks(WIDTH)
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .map(|(column, x)| {
+                    if x.abs_diff(column as i64) <= 1 {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    Generated::new(program.join("\n")).with_answers(part_one, screen.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day10;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            generate(1, &Params::default()),
+            generate(1, &Params::default())
+        );
+        for seed in 0..20 {
+            generate(seed, &Params::default()).assert_solved_by::<Day10>();
+        }
+        let generated = generate(0, &Params { noops: 1.0 });
+        assert_eq!(
+            generated.part_one,
+            Some((20 + 60 + 100 + 140 + 180 + 220).into())
+        );
+        generated.assert_solved_by::<Day10>();
+    }
+}
@@ -0,0 +1,175 @@
+pub mod generate;
+
+use aoc_common::{
+    parse,
+    simulation::{Simulation, State},
+    Error, Result, Solution,
+};
+use aoc_macros::{aoc, aoc_example};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ElfCPU {
+    current_cycle: u32,
+    command_stack: Vec<Opcode>,
+    x_value: i32,
+    max_cycle: u32,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct Opcode {
+    command: Command,
+    lifespan: u8,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Command {
+    Add(i32),
+    Pass,
+}
+
+impl ElfCPU {
+    fn new() -> Self {
+        ElfCPU {
+            current_cycle: 1,
+            command_stack: Vec::new(),
+            x_value: 1,
+            max_cycle: 0,
+        }
+    }
+
+    fn tick(&mut self) {
+        let mut is_remove = false;
+        if let Some(opcode) = self.command_stack.first_mut() {
+            if opcode.lifespan == 0 {
+                let value = ElfCPU::get_increment_register_with_command(&opcode.command);
+                self.x_value += value;
+                is_remove = true;
+            } else {
+                opcode.lifespan -= 1;
+            }
+        }
+        if is_remove {
+            self.command_stack.remove(0);
+        }
+        // Update cycles
+        self.current_cycle += 1;
+        self.max_cycle -= 1;
+    }
+
+    fn push_opcode(&mut self, opcode: Opcode) {
+        self.command_stack.push(opcode);
+    }
+
+    fn get_increment_register_with_command(command: &Command) -> i32 {
+        match command {
+            Command::Add(value) => *value,
+            Command::Pass => 0,
+        }
+    }
+}
+
+/// One tick is one clock cycle, until the program has run.
+impl State for ElfCPU {
+    fn step(&mut self) -> bool {
+        if self.max_cycle == 0 {
+            return false;
+        }
+        self.tick();
+        true
+    }
+}
+
+pub struct Day10;
+
+#[aoc(day = 10)]
+impl Solution for Day10 {
+    // a CPU loaded with the whole program
+    type Input = ElfCPU;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+}
+
+fn parse_input(input: &str) -> Result<ElfCPU> {
+    let mut cpu = ElfCPU::new();
+    for line in input.lines() {
+        match line {
+            "noop" => {
+                cpu.push_opcode(Opcode {
+                    command: Command::Pass,
+                    lifespan: 0,
+                });
+                cpu.max_cycle += 1;
+            }
+            add => {
+                let Some(add_value) = add.strip_prefix("addx ") else {
+                    return Err(Error::at(
+                        input,
+                        line,
+                        "expected \"noop\" or \"addx <value>\"",
+                    ));
+                };
+                cpu.push_opcode(Opcode {
+                    command: Command::Add(parse::number(input, add_value)?),
+                    lifespan: 1,
+                });
+                cpu.max_cycle += 2;
+            }
+        }
+    }
+    Ok(cpu)
+}
+
+#[aoc(day = 10, part = 1)]
+#[aoc_example(expected = 13140)]
+fn part_one(cpu: &ElfCPU) -> i32 {
+    let mut x_values = vec![];
+    let cycles: Vec<i32> = vec![20, 60, 100, 140, 180, 220];
+    Simulation::new(cpu.clone())
+        .observe(|_, cpu: &ElfCPU| {
+            if cycles.contains(&(cpu.current_cycle as i32)) {
+                x_values.push(cpu.x_value);
+            }
+        })
+        .run();
+
+    cycles.iter().zip(x_values.iter()).map(|(a, b)| a * b).sum()
+}
+
+#[aoc(day = 10, part = 2)]
+#[aoc_example(expected = "##..##..##..##..##..##..##..##..##..##..\n\
+                ###...###...###...###...###...###...###.\n\
+                ####....####....####....####....####....\n\
+                #####.....#####.....#####.....#####.....\n\
+                ######......######......######......####\n\
+                #######.......#######.......#######.....")]
+fn part_two(cpu: &ElfCPU) -> String {
+    let mut simulation = Simulation::new(cpu.clone());
+    let mut res = String::new();
+    let cycles: Vec<i32> = vec![41, 81, 121, 161, 201, 241];
+
+    // dirty
+    let mut count = 0;
+
+    loop {
+        let x_value = simulation.state().x_value;
+        let sprite = [x_value - 1, x_value, x_value + 1];
+        if !simulation.step() {
+            break;
+        }
+        if sprite.contains(&count) {
+            res.push('#');
+        } else {
+            res.push('.');
+        }
+        count += 1;
+        // no newline after the last row
+        let cpu = simulation.state();
+        if cycles.contains(&(cpu.current_cycle as i32)) && cpu.max_cycle > 0 {
+            res.push('\n');
+            count = 0;
+        }
+    }
+    res
+}
@@ -0,0 +1,143 @@
+//! Random monkey notes, with both answers.
+//!
+//! Monkeys test divisibility by distinct primes, and one of them squares the
+//! worry level. Part one lets worry levels grow for twenty rounds, so notes
+//! that would overflow them are drawn again.
+
+use aoc_common::generate::{Generated, Rng};
+
+use crate::{Monkey, Monkeys, Operand};
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// Three to ten.
+    pub monkeys: usize,
+    pub max_items: usize,
+}
+
+/// About the size of a real input.
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            monkeys: 8,
+            max_items: 8,
+        }
+    }
+}
+
+const PRIMES: [u128; 10] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
+
+pub fn generate(seed: u64, params: &Params) -> Generated {
+    assert!(
+        (3..=PRIMES.len()).contains(&params.monkeys),
+        "between 3 and {} monkeys",
+        PRIMES.len()
+    );
+    let mut rng = Rng::new(seed);
+    loop {
+        let monkeys = draw(&mut rng, params);
+        let Some(part_one) = monkey_business(&monkeys, 20, |worry| worry / 3) else {
+            continue;
+        };
+        let lcm: u128 = monkeys
+            .monkeys
+            .iter()
+            .map(|monkey| monkey.divisible_condition_factor)
+            .product();
+        let part_two = monkey_business(&monkeys, 10_000, |worry| worry % lcm)
+            .expect("worry levels stay below the square of the lcm");
+        return Generated::new(monkeys.to_string()).with_answers(part_one, part_two);
+    }
+}
+
+fn draw(rng: &mut Rng, params: &Params) -> Monkeys {
+    let mut divisors = PRIMES;
+    rng.shuffle(&mut divisors);
+    let squaring = rng.below(params.monkeys);
+    let mut monkeys = Monkeys::new();
+    for (index, &divisor) in divisors[..params.monkeys].iter().enumerate() {
+        let items = (0..rng.between(1..=params.max_items as i64))
+            .map(|_| rng.between(50..=99) as u128)
+            .collect();
+        let inspection_factor = if index == squaring {
+            ('*', Operand::Old)
+        } else if rng.chance(0.5) {
+            ('+', Operand::Value(rng.between(1..=8) as u128))
+        } else {
+            ('*', Operand::Value(rng.between(2..=19) as u128))
+        };
+        // two different monkeys other than this one
+        let mut others: Vec<u8> = (0..params.monkeys as u8)
+            .filter(|&other| usize::from(other) != index)
+            .collect();
+        rng.shuffle(&mut others);
+        monkeys.insert_monkey(Monkey {
+            items,
+            inspection_factor,
+            divisible_condition_factor: divisor,
+            target_monkeys: (others[0], others[1]),
+        });
+    }
+    monkeys
+}
+
+// Plays `rounds` rounds the slow way, calming worry levels with `relief`
+// after each inspection; `None` if a worry level overflows.
+fn monkey_business(
+    monkeys: &Monkeys,
+    rounds: usize,
+    relief: impl Fn(u128) -> u128,
+) -> Option<u128> {
+    let mut items: Vec<Vec<u128>> = monkeys
+        .monkeys
+        .iter()
+        .map(|monkey| monkey.items.clone())
+        .collect();
+    let mut inspections = vec![0; items.len()];
+    for _ in 0..rounds {
+        for (index, monkey) in monkeys.monkeys.iter().enumerate() {
+            for worry in std::mem::take(&mut items[index]) {
+                let operand = match monkey.inspection_factor.1 {
+                    Operand::Old => worry,
+                    Operand::Value(value) => value,
+                };
+                let worry = relief(match monkey.inspection_factor.0 {
+                    '+' => worry.checked_add(operand)?,
+                    _ => worry.checked_mul(operand)?,
+                });
+                let target = if worry.is_multiple_of(monkey.divisible_condition_factor) {
+                    monkey.target_monkeys.0
+                } else {
+                    monkey.target_monkeys.1
+                };
+                items[usize::from(target)].push(worry);
+                inspections[index] += 1;
+            }
+        }
+    }
+    inspections.sort_unstable_by(|a, b| b.cmp(a));
+    Some(inspections[0] * inspections[1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day11;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            generate(1, &Params::default()),
+            generate(1, &Params::default())
+        );
+        // part two is played out round by round, which takes a while
+        for seed in 0..5 {
+            generate(seed, &Params::default()).assert_solved_by::<Day11>();
+        }
+        let most = Params {
+            monkeys: 10,
+            max_items: 3,
+        };
+        generate(0, &most).assert_solved_by::<Day11>();
+    }
+}
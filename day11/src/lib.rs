@@ -0,0 +1,639 @@
+pub mod generate;
+
+use std::fmt;
+
+use aoc_common::{
+    cycle, math, parse,
+    simulation::{Simulation, State},
+    Error, Result, Solution,
+};
+use aoc_macros::{aoc, aoc_example};
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Monkeys {
+    monkeys: Vec<Monkey>,
+    inspection_counts: Vec<u128>,
+    // a worry level grew past u128, leaving the round half played
+    overflowed: bool,
+}
+
+/// One tick is a round of every monkey taking its turn, worry levels being
+/// divided by three as in part one. The monkeys only stop once a worry level
+/// overflows.
+impl State for Monkeys {
+    fn step(&mut self) -> bool {
+        if self.overflowed || self.play_round().is_none() {
+            self.overflowed = true;
+            return false;
+        }
+        true
+    }
+}
+
+impl Monkeys {
+    fn new() -> Self {
+        Self {
+            monkeys: Vec::new(),
+            inspection_counts: Vec::new(),
+            overflowed: false,
+        }
+    }
+
+    fn insert_monkey(&mut self, monkey: Monkey) {
+        self.monkeys.push(monkey);
+        self.inspection_counts.push(0);
+    }
+
+    // `None` if a worry level overflows
+    fn play_round(&mut self) -> Option<()> {
+        for index in 0..self.monkeys.len() {
+            let mut new_items: Vec<(usize, u128)> = Vec::new();
+            for item in self.monkeys[index].items.iter() {
+                let inspected_item = self.monkeys[index].inspect(*item)?;
+
+                // increment inspection count
+                self.inspection_counts[index] += 1;
+
+                // get monkey to throw to
+                let target_monkey = self.monkeys[index].throw_target(inspected_item);
+                new_items.push((target_monkey as usize, inspected_item));
+            }
+
+            // Clearing monkey's items
+            self.monkeys[index].items.clear();
+
+            for (target_monkey, item) in new_items {
+                // Get a mutable reference to the target monkey
+                let target_monkey = &mut self.monkeys[target_monkey];
+
+                // Add the item to the target monkey's items
+                target_monkey.items.push(item);
+            }
+        }
+        Some(())
+    }
+
+    /// The least common multiple of the divisors, `None` if it does not fit
+    /// in a u128.
+    fn lcm(&self) -> Option<u128> {
+        self.monkeys.iter().try_fold(1, |acc, monkey| {
+            math::checked_lcm(acc, monkey.divisible_condition_factor)
+        })
+    }
+
+    // part two round by round, to check the cycles against
+    #[cfg(test)]
+    fn play_round_part_two(&mut self) {
+        let lcm = self.lcm().unwrap();
+        for index in 0..self.monkeys.len() {
+            let mut new_items: Vec<(usize, u128)> = Vec::new();
+            for item in self.monkeys[index].items.iter() {
+                let inspected_item = self.monkeys[index].inspect_part_two(*item).unwrap();
+
+                // increment inspection count
+                self.inspection_counts[index] += 1;
+
+                // get monkey to throw to
+                let target_monkey = self.monkeys[index].throw_target(inspected_item);
+
+                let item_reduced = inspected_item % lcm;
+
+                new_items.push((target_monkey as usize, item_reduced));
+            }
+
+            // Clearing monkey's items
+            self.monkeys[index].items.clear();
+
+            for (target_monkey, item) in new_items {
+                // Get a mutable reference to the target monkey
+
+                let target_monkey = &mut self.monkeys[target_monkey];
+
+                // Add the item to the target monkey's items
+                target_monkey.items.push(item);
+            }
+        }
+    }
+}
+
+/// Writes the notes back out the way they are parsed.
+impl fmt::Display for Monkeys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, monkey) in self.monkeys.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            let items: Vec<String> = monkey.items.iter().map(u128::to_string).collect();
+            writeln!(f, "Monkey {}:", index)?;
+            writeln!(f, "  Starting items: {}", items.join(", "))?;
+            writeln!(
+                f,
+                "  Operation: new = old {} {}",
+                monkey.inspection_factor.0, monkey.inspection_factor.1
+            )?;
+            writeln!(
+                f,
+                "  Test: divisible by {}",
+                monkey.divisible_condition_factor
+            )?;
+            writeln!(
+                f,
+                "    If true: throw to monkey {}",
+                monkey.target_monkeys.0
+            )?;
+            writeln!(
+                f,
+                "    If false: throw to monkey {}",
+                monkey.target_monkeys.1
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// One item on its own, thrown from monkey to monkey with its worry level
+/// reduced as in part two. Items never affect each other, so each cycles on
+/// its own; a tick is a round, in which it may be inspected several times.
+#[derive(Debug, Clone)]
+struct Item<'a> {
+    monkeys: &'a [Monkey],
+    lcm: u128,
+    holder: usize,
+    worry: u128,
+    inspection_counts: Vec<u128>,
+    // an inspection overflowed, which ends the item's run
+    overflowed: bool,
+}
+
+impl State for Item<'_> {
+    fn step(&mut self) -> bool {
+        // the item moves on within the round while thrown to a later monkey
+        loop {
+            let monkey = &self.monkeys[self.holder];
+            let Some(inspected) = monkey.inspect_part_two(self.worry) else {
+                self.overflowed = true;
+                return false;
+            };
+            self.inspection_counts[self.holder] += 1;
+            let target = monkey.throw_target(inspected) as usize;
+            self.worry = inspected % self.lcm;
+            let thrown_back = target <= self.holder;
+            self.holder = target;
+            if thrown_back {
+                return true;
+            }
+        }
+    }
+}
+
+impl Monkeys {
+    /// The inspection counts of part two after `rounds` rounds, found by
+    /// following each item until it cycles rather than playing every round.
+    /// `None` if the divisors' lcm or a worry level overflows.
+    fn inspection_counts_after(&self, rounds: usize) -> Option<Vec<u128>> {
+        let lcm = self.lcm()?;
+        let mut inspection_counts = vec![0; self.monkeys.len()];
+        for (holder, monkey) in self.monkeys.iter().enumerate() {
+            for &worry in &monkey.items {
+                let item = Item {
+                    monkeys: &self.monkeys,
+                    lcm,
+                    holder,
+                    // every test divides the lcm, so this throws it the same
+                    worry: worry % lcm,
+                    inspection_counts: vec![0; self.monkeys.len()],
+                    overflowed: false,
+                };
+                // an item may take longer to come back than there are rounds
+                match cycle::find_cycle_within(item, |item| (item.holder, item.worry), rounds) {
+                    Ok(history) => {
+                        for (index, count) in inspection_counts.iter_mut().enumerate() {
+                            *count +=
+                                history.extrapolate(rounds, |item| item.inspection_counts[index]);
+                        }
+                    }
+                    Err(item) if item.overflowed => return None,
+                    Err(item) => {
+                        for (count, item_count) in
+                            inspection_counts.iter_mut().zip(&item.inspection_counts)
+                        {
+                            *count += item_count;
+                        }
+                    }
+                }
+            }
+        }
+        Some(inspection_counts)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Monkey {
+    items: Vec<u128>,
+    inspection_factor: (char, Operand),
+    divisible_condition_factor: u128,
+    // If true, throw item to target_monkeys.0, else target_monkeys.1
+    target_monkeys: (u8, u8),
+}
+
+/// The right-hand side of a monkey's operation.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Operand {
+    Old,
+    Value(u128),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Old => write!(f, "old"),
+            Operand::Value(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Monkey {
+    fn operand(&self, item: u128) -> u128 {
+        match self.inspection_factor.1 {
+            Operand::Old => item,
+            Operand::Value(value) => value,
+        }
+    }
+
+    // `None` when the worry level overflows, as for part two
+    fn inspect(&self, item: u128) -> Option<u128> {
+        Some(self.inspect_part_two(item)? / 3)
+    }
+
+    fn inspect_part_two(&self, item: u128) -> Option<u128> {
+        match self.inspection_factor.0 {
+            '+' => item.checked_add(self.operand(item)),
+            '*' => item.checked_mul(self.operand(item)),
+            _ => panic!("Invalid inspection factor"),
+        }
+    }
+
+    fn divisible(&self, item: u128) -> bool {
+        item.is_multiple_of(self.divisible_condition_factor)
+    }
+
+    fn throw_target(&self, inspected_item: u128) -> u8 {
+        if self.divisible(inspected_item) {
+            self.target_monkeys.0
+        } else {
+            self.target_monkeys.1
+        }
+    }
+}
+
+/// Parses one monkey's block of notes, from its `Monkey <n>:` line on.
+pub fn parse_monkey(input: &str) -> Result<Monkey> {
+    let mut lines = input.lines();
+    let mut field = |prefix: &str| {
+        let line = lines.next().ok_or_else(|| {
+            let end = &input[input.len()..];
+            Error::at(input, end, format!("expected {:?}", prefix.trim_start()))
+        })?;
+        parse::strip_prefix(input, line, prefix)
+    };
+    let header = field("Monkey ")?;
+    let number = header
+        .strip_suffix(':')
+        .ok_or_else(|| Error::at(input, &header[header.len()..], "expected ':'"))?;
+    parse::number::<usize>(input, number)?;
+    let items: Vec<u128> = match field("  Starting items: ")? {
+        "" => Vec::new(),
+        items => items
+            .split(", ")
+            .map(|item| parse::number(input, item))
+            .collect::<Result<_>>()?,
+    };
+    let (operator, operand) = parse::split_once(input, field("  Operation: new = old ")?, " ")?;
+
+    let inspection_mul_factor: (char, Operand) = (
+        match operator {
+            "+" => '+',
+            "*" => '*',
+            _ => return Err(Error::at(input, operator, "expected '+' or '*'")),
+        },
+        match operand {
+            "old" => Operand::Old,
+            operand => Operand::Value(parse::number(input, operand)?),
+        },
+    );
+    let divisor = field("  Test: divisible by ")?;
+    let divisible_condition_factor = parse::number(input, divisor)?;
+    if divisible_condition_factor == 0 {
+        return Err(Error::at(input, divisor, "cannot test divisibility by 0"));
+    }
+    let first_target_monkey = parse::number(input, field("    If true: throw to monkey ")?)?;
+    let second_target_monkey = parse::number(input, field("    If false: throw to monkey ")?)?;
+    Ok(Monkey {
+        items,
+        inspection_factor: inspection_mul_factor,
+        divisible_condition_factor,
+        target_monkeys: (first_target_monkey, second_target_monkey),
+    })
+}
+
+fn parse_input(input: &str) -> Result<Monkeys> {
+    let mut monkeys = Monkeys::new();
+    let blocks: Vec<&str> = input.split("\n\n").collect();
+    for monkey_input in &blocks {
+        let monkey = parse_monkey(monkey_input).map_err(|err| err.rebase(input, monkey_input))?;
+        monkeys.insert_monkey(monkey);
+    }
+    // every monkey must throw to one that exists
+    for (monkey, block) in monkeys.monkeys.iter().zip(&blocks) {
+        for target in [monkey.target_monkeys.0, monkey.target_monkeys.1] {
+            if usize::from(target) >= blocks.len() {
+                let line = block
+                    .lines()
+                    .find(|line| line.ends_with(&format!(" monkey {}", target)))
+                    .unwrap_or(block);
+                return Err(Error::at(
+                    input,
+                    line,
+                    format!("there is no monkey {}", target),
+                ));
+            }
+        }
+    }
+    Ok(monkeys)
+}
+
+pub struct Day11;
+
+#[aoc(day = 11)]
+impl Solution for Day11 {
+    type Input = Monkeys;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+}
+
+#[aoc(day = 11, part = 1)]
+#[aoc_example(expected = 10605)]
+fn business_after_20_rounds(monkeys: &Monkeys) -> Option<u128> {
+    let mut simulation = Simulation::new(monkeys.clone());
+    simulation.run_for(20);
+    if simulation.state().overflowed {
+        return None;
+    }
+    monkey_business(simulation.state())
+}
+
+#[aoc(day = 11, part = 2)]
+#[aoc_example(expected = 2713310158_u64)]
+fn business_after_10000_rounds(monkeys: &Monkeys) -> Option<u128> {
+    let inspection_counts = monkeys.inspection_counts_after(10000)?;
+    monkey_business(&Monkeys {
+        inspection_counts,
+        ..monkeys.clone()
+    })
+}
+
+// product of the two highest inspection counts, `None` with fewer than two
+// monkeys or if it overflows
+fn monkey_business(monkeys: &Monkeys) -> Option<u128> {
+    let mut inspection_counts = monkeys.inspection_counts.clone();
+    inspection_counts.sort();
+    inspection_counts.reverse();
+    let [highest, second_highest, ..] = inspection_counts[..] else {
+        return None;
+    };
+    highest.checked_mul(second_highest)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use aoc_common::{Answer, Part};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_monkey() {
+        let input = include_str!("../../inputs/examples/day11.txt")
+            .split("\n\n")
+            .collect::<Vec<&str>>();
+        let first_input = input[0];
+        let monkey = parse_monkey(first_input).unwrap();
+        assert_eq!(
+            monkey,
+            Monkey {
+                items: vec![79, 98],
+                inspection_factor: ('*', Operand::Value(19)),
+                divisible_condition_factor: 23,
+                target_monkeys: (2, 3),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = include_str!("../../inputs/examples/day11.txt");
+        let monkeys = parse_input(input).unwrap();
+        assert_eq!(
+            monkeys.monkeys[0],
+            Monkey {
+                items: vec![79, 98],
+                inspection_factor: ('*', Operand::Value(19)),
+                divisible_condition_factor: 23,
+                target_monkeys: (2, 3),
+            }
+        );
+        assert_eq!(
+            monkeys.monkeys[1],
+            Monkey {
+                items: vec![54, 65, 75, 74],
+                inspection_factor: ('+', Operand::Value(6)),
+                divisible_condition_factor: 19,
+                target_monkeys: (2, 0),
+            }
+        );
+        assert_eq!(
+            monkeys.monkeys[2],
+            Monkey {
+                items: vec![79, 60, 97],
+                inspection_factor: ('*', Operand::Old),
+                divisible_condition_factor: 13,
+                target_monkeys: (1, 3),
+            }
+        );
+        assert_eq!(
+            monkeys.monkeys[3],
+            Monkey {
+                items: vec![74],
+                inspection_factor: ('+', Operand::Value(3)),
+                divisible_condition_factor: 17,
+                target_monkeys: (0, 1),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = include_str!("../../inputs/examples/day11.txt");
+        let position = |input: &str| match parse_input(input).unwrap_err() {
+            Error::Parse(err) => (err.line, err.column, err.span().to_string()),
+            err => panic!("unexpected error: {}", err),
+        };
+        assert_eq!(
+            position(&input.replace("old + 6", "old - 6")),
+            (10, 24, "-".to_string())
+        );
+        assert_eq!(
+            position(&input.replace("divisible by 19", "divisible by x")),
+            (11, 22, "x".to_string())
+        );
+        assert_eq!(
+            position(&input.replace("throw to monkey 0", "throw to monkey 7")),
+            (13, 1, "    If false: throw to monkey 7".to_string())
+        );
+        assert_eq!(
+            position(&input.replace("  Test: divisible by 17\n", "")),
+            (25, 1, "    If true: throw to".to_string())
+        );
+    }
+
+    fn monkeys() -> impl Strategy<Value = Monkeys> {
+        (1..6_u8).prop_flat_map(|count| {
+            let operand = prop_oneof![Just(Operand::Old), any::<u128>().prop_map(Operand::Value)];
+            let monkey = (
+                prop::collection::vec(any::<u128>(), 0..5),
+                prop::sample::select(vec!['+', '*']),
+                operand,
+                1..=u128::MAX,
+                (0..count, 0..count),
+            )
+                .prop_map(
+                    |(items, operator, operand, divisor, target_monkeys)| Monkey {
+                        items,
+                        inspection_factor: (operator, operand),
+                        divisible_condition_factor: divisor,
+                        target_monkeys,
+                    },
+                );
+            prop::collection::vec(monkey, usize::from(count)).prop_map(|list| {
+                let mut monkeys = Monkeys::new();
+                list.into_iter()
+                    .for_each(|monkey| monkeys.insert_monkey(monkey));
+                monkeys
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(monkeys in monkeys()) {
+            let rendered = monkeys.to_string();
+            for (block, monkey) in rendered.split("\n\n").zip(&monkeys.monkeys) {
+                prop_assert_eq!(&parse_monkey(block).unwrap(), monkey);
+            }
+            prop_assert_eq!(parse_input(&rendered).unwrap(), monkeys);
+        }
+
+        #[test]
+        fn test_parse_monkey_never_panics(input in "(Monkey 0:|  Starting items: 1, 2|  Operation: new = old [+*-] (old|7)|  Test: divisible by [0-9]|    If (true|false): throw to monkey [0-9]|\n){0,12}") {
+            let _ = parse_monkey(&input);
+        }
+    }
+
+    #[test]
+    fn test_inspection_counts_after() {
+        let input = include_str!("../../inputs/examples/day11.txt");
+        let original = parse_input(input).unwrap();
+        for rounds in [0, 1, 20, 1000] {
+            let mut monkeys = original.clone();
+            for _ in 0..rounds {
+                monkeys.play_round_part_two();
+            }
+            assert_eq!(
+                original.inspection_counts_after(rounds),
+                Some(monkeys.inspection_counts)
+            );
+        }
+        // all 10 items are inspected at least once a round
+        let counts = original.inspection_counts_after(1_000_000_000).unwrap();
+        assert!(counts.iter().sum::<u128>() >= 10_000_000_000);
+    }
+
+    #[test]
+    fn test_inspection_counts_after_long_cycle() {
+        // monkeys passing an item on with one added each time, testing large
+        // primes, so that it takes about 5 * 10^11 rounds to come back
+        let input = [999_983, 1_000_003]
+            .iter()
+            .enumerate()
+            .map(|(index, divisor)| {
+                let next = (index + 1) % 2;
+                format!(
+                    "Monkey {}:\n  Starting items: {}\n  Operation: new = old + 1\n  \
+                     Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                     If false: throw to monkey {}",
+                    index,
+                    index + 1,
+                    divisor,
+                    next,
+                    next
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let original = parse_input(&input).unwrap();
+        let mut monkeys = original.clone();
+        for _ in 0..10000 {
+            monkeys.play_round_part_two();
+        }
+        assert_eq!(
+            original.inspection_counts_after(10000),
+            Some(monkeys.inspection_counts)
+        );
+    }
+
+    // one monkey's notes throwing to itself, as in the example but the
+    // operation, divisor and items
+    fn lone_monkey(items: &str, operation: &str, divisor: u128) -> String {
+        format!(
+            "Monkey 0:\n  Starting items: {}\n  Operation: new = old {}\n  \
+             Test: divisible by {}\n    If true: throw to monkey 0\n    \
+             If false: throw to monkey 0",
+            items, operation, divisor
+        )
+    }
+
+    #[test]
+    fn test_no_monkey_business() {
+        // a single monkey has no second busiest to multiply with
+        let monkeys = parse_input(&lone_monkey("1, 2", "+ 1", 2)).unwrap();
+        assert_eq!(Day11::solve(&monkeys, Part::One), Answer::NoSolution);
+        assert_eq!(Day11::solve(&monkeys, Part::Two), Answer::NoSolution);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let pair = |first: &str, second: &str| {
+            let second = second.replace("Monkey 0", "Monkey 1");
+            parse_input(&format!("{}\n\n{}", first, second)).unwrap()
+        };
+        let huge = (u128::MAX / 2).to_string();
+
+        // squaring a worry level that stays large
+        let squared = pair(&lone_monkey(&huge, "* old", 1), &lone_monkey("", "+ 1", 1));
+        assert_eq!(Day11::solve(&squared, Part::One), Answer::NoSolution);
+        // divisors whose lcm does not fit
+        let divisors = pair(
+            &lone_monkey("1", "+ 1", u128::MAX),
+            &lone_monkey("1", "+ 1", u128::MAX - 1),
+        );
+        assert_eq!(Day11::solve(&divisors, Part::Two), Answer::NoSolution);
+        // a worry level below the lcm that still overflows once multiplied
+        let multiplied = pair(
+            &lone_monkey(&huge, &format!("* {}", huge), u128::MAX),
+            &lone_monkey("", "+ 1", 1),
+        );
+        assert_eq!(Day11::solve(&multiplied, Part::Two), Answer::NoSolution);
+    }
+}
@@ -0,0 +1,105 @@
+//! Random heightmaps that always have a way up.
+//!
+//! A monotone staircase is carved from `S` to `E`, rising at most one letter
+//! per step, over terrain of random heights.
+
+use aoc_common::generate::{Generated, Rng};
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// Together at least 27, to fit a staircase from `a` to `z`.
+    pub width: usize,
+    pub height: usize,
+}
+
+/// About the size of a real input.
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            width: 70,
+            height: 41,
+        }
+    }
+}
+
+pub fn generate(seed: u64, params: &Params) -> Generated {
+    let (width, height) = (params.width, params.height);
+    assert!(width + height >= 27, "too small to climb from a to z");
+    let mut rng = Rng::new(seed);
+    let mut map: Vec<Vec<u8>> = (0..height)
+        .map(|_| (0..width).map(|_| b'a' + rng.below(26) as u8).collect())
+        .collect();
+
+    let mut cell = || (rng.below(width), rng.below(height));
+    let (start, end) = loop {
+        let (start, end) = (cell(), cell());
+        if start.0.abs_diff(end.0) + start.1.abs_diff(end.1) >= 25 {
+            break (start, end);
+        }
+    };
+
+    // every step goes towards `E`, so the staircase never crosses itself
+    let (dx, dy) = (end.0.abs_diff(start.0), end.1.abs_diff(start.1));
+    let mut steps: Vec<bool> = (0..dx + dy).map(|step| step < dx).collect();
+    rng.shuffle(&mut steps);
+    let (mut x, mut y) = start;
+    for (index, &horizontal) in steps.iter().enumerate() {
+        if horizontal {
+            x = if end.0 > x { x + 1 } else { x - 1 };
+        } else {
+            y = if end.1 > y { y + 1 } else { y - 1 };
+        }
+        // from a after no steps to z after all of them
+        map[y][x] = b'a' + ((index + 1) * 25 / steps.len()) as u8;
+    }
+    map[start.1][start.0] = b'S';
+    map[end.1][end.0] = b'E';
+
+    let rows: Vec<String> = map
+        .into_iter()
+        .map(|row| String::from_utf8(row).unwrap())
+        .collect();
+    Generated::new(rows.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day12;
+    use aoc_common::{Answer, Part, Solution};
+
+    fn assert_climbable(params: &Params, seed: u64) {
+        let heightmap = Day12::parse(&generate(seed, params).input).unwrap();
+        for part in Part::ALL {
+            let Answer::Number(steps) = Day12::solve(&heightmap, part) else {
+                panic!("no way up for seed {}", seed);
+            };
+            assert!(steps >= 1);
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            generate(1, &Params::default()),
+            generate(1, &Params::default())
+        );
+        for seed in 0..20 {
+            assert_climbable(&Params::default(), seed);
+            let line = Params {
+                width: 27,
+                height: 1,
+            };
+            assert_climbable(&line, seed);
+        }
+    }
+
+    #[test]
+    fn test_generate_large() {
+        let large = Params {
+            width: 300,
+            height: 300,
+        };
+        assert_climbable(&large, 0);
+    }
+}
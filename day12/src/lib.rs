@@ -0,0 +1,118 @@
+pub mod generate;
+
+use aoc_common::{search, Error, Result, Solution};
+use aoc_grid::{Grid, Pos};
+use aoc_macros::{aoc, aoc_example};
+use std::collections::HashMap;
+
+fn parse_input(input: &str) -> Result<Grid<u8>> {
+    Grid::parse(input, parse_char)
+}
+
+fn parse_char(c: char) -> Option<u8> {
+    let mut map = HashMap::new();
+
+    for (i, letter) in "abcdefghijklmnopqrstuvwxyz".chars().enumerate() {
+        map.insert(letter, i as u8);
+    }
+
+    // Special case: map 's' to 0 and 'e' to 25
+    map.insert('S', 0);
+    map.insert('E', 25);
+
+    map.get(&c).cloned()
+}
+
+// find start (S) and end (E) positions
+fn get_start_end(input: &str) -> Result<(Pos, Pos)> {
+    let map = Grid::parse(input, Some)?;
+    let find = |marker: char| {
+        map.position(|&c| c == marker)
+            .ok_or_else(|| Error::InvalidInput(format!("no {:?} on the heightmap", marker)))
+    };
+    Ok((find('S')?, find('E')?))
+}
+
+// fewest steps from the nearest of `starts` to `end`
+fn find_shortest_path(
+    grid: &Grid<u8>,
+    starts: impl IntoIterator<Item = Pos>,
+    end: Pos,
+) -> Option<usize> {
+    // climb at most one step up, or go down any height
+    let climbable = |position: &Pos| {
+        let height = grid[*position];
+        grid.neighbours4(*position)
+            .filter(move |&next| grid[next] <= height + 1)
+    };
+    search::bfs(starts, climbable, |&position| position == end).goal_cost()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heightmap {
+    grid: Grid<u8>,
+    start: Pos,
+    end: Pos,
+}
+
+pub struct Day12;
+
+#[aoc(day = 12)]
+impl Solution for Day12 {
+    type Input = Heightmap;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (start, end) = get_start_end(input)?;
+        Ok(Heightmap {
+            grid: parse_input(input)?,
+            start,
+            end,
+        })
+    }
+}
+
+#[aoc(day = 12, part = 1)]
+#[aoc_example(expected = 31)]
+fn fewest_steps_from_start(heightmap: &Heightmap) -> Option<usize> {
+    find_shortest_path(&heightmap.grid, [heightmap.start], heightmap.end)
+}
+
+#[aoc(day = 12, part = 2)]
+#[aoc_example(expected = 29)]
+fn fewest_steps_from_any_a(heightmap: &Heightmap) -> Option<usize> {
+    let grid = &heightmap.grid;
+    let all_possible_start_position = grid
+        .iter()
+        .filter(|(_, &height)| height == 0)
+        .map(|(position, _)| position);
+
+    // searching from every start at once finds the nearest one
+    find_shortest_path(grid, all_possible_start_position, heightmap.end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let input = include_str!("../../inputs/examples/day12.txt");
+        let parsed = parse_input(input).unwrap();
+        let expected = vec![
+            vec![0, 0, 1, 16, 15, 14, 13, 12],
+            vec![0, 1, 2, 17, 24, 23, 23, 11],
+            vec![0, 2, 2, 18, 25, 25, 23, 10],
+            vec![0, 2, 2, 19, 20, 21, 22, 9],
+            vec![0, 1, 3, 4, 5, 6, 7, 8],
+        ];
+        assert_eq!(parsed, Grid::from_rows(expected).unwrap());
+    }
+
+    #[test]
+    fn test_get_start_end() {
+        let input = include_str!("../../inputs/examples/day12.txt");
+        let (start, end) = get_start_end(input).unwrap();
+        assert_eq!(start, Pos::new(0, 0));
+        assert_eq!(end, Pos::new(5, 2));
+    }
+}
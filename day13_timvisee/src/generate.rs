@@ -0,0 +1,92 @@
+//! Random packet pairs.
+
+use aoc_common::generate::{Generated, Rng};
+
+use crate::{Item, MAX_DEPTH};
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub pairs: usize,
+    /// At most [`MAX_DEPTH`].
+    pub max_depth: usize,
+    pub max_len: usize,
+    /// At most 255.
+    pub max_value: u8,
+}
+
+/// About the size of a real input.
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            pairs: 150,
+            max_depth: 4,
+            max_len: 5,
+            max_value: 10,
+        }
+    }
+}
+
+pub fn generate(seed: u64, params: &Params) -> Generated {
+    assert!(
+        params.max_depth <= MAX_DEPTH,
+        "packets nest lists at most {} deep",
+        MAX_DEPTH
+    );
+    let mut rng = Rng::new(seed);
+    let pairs: Vec<String> = (0..params.pairs)
+        .map(|_| {
+            let left = list(&mut rng, params, 1);
+            let right = list(&mut rng, params, 1);
+            format!("{}\n{}", left, right)
+        })
+        .collect();
+    Generated::new(pairs.join("\n\n"))
+}
+
+// A list at `depth`, holding values and lists no deeper than allowed
+fn list(rng: &mut Rng, params: &Params, depth: usize) -> Item {
+    let items = (0..rng.between(0..=params.max_len as i64))
+        .map(|_| {
+            if depth < params.max_depth && rng.chance(0.3) {
+                list(rng, params, depth + 1)
+            } else {
+                Item::I(rng.between(0..=i64::from(params.max_value)) as u8)
+            }
+        })
+        .collect();
+    Item::L(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day13;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            generate(1, &Params::default()),
+            generate(1, &Params::default())
+        );
+        for seed in 0..20 {
+            let generated = generate(seed, &Params::default());
+            let pairs = Day13::parse(&generated.input).unwrap();
+            assert_eq!(pairs.len(), 150);
+            generated.assert_solved_by::<Day13>();
+        }
+        let extreme = Params {
+            pairs: 10,
+            max_depth: MAX_DEPTH,
+            max_len: 3,
+            max_value: 255,
+        };
+        let generated = generate(0, &extreme);
+        let written: Vec<String> = Day13::parse(&generated.input)
+            .unwrap()
+            .iter()
+            .map(|(left, right)| format!("{}\n{}", left, right))
+            .collect();
+        assert_eq!(written.join("\n\n"), generated.input);
+    }
+}
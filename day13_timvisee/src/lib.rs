@@ -0,0 +1,264 @@
+/* Part one:
+ * DISCLAIMER: I DID NOT AUTHOR THIS CODE
+ * It was taken from Tim Visee's https://github.com/timvisee/advent-of-code-2022/blob/master/day13a/src/main.rs
+ * I wanted to explore new ways to parse complicated inputs. Will add comments to help understand
+ * the process
+ */
+
+pub mod generate;
+
+use aoc_common::{parallel, Answer, Error, Result, Solution};
+use aoc_macros::aoc;
+use nom::{alt, char, delimited, map, named, separated_list0, separated_pair, tag};
+use std::{cmp::Ordering, fmt};
+
+pub struct Day13;
+
+#[aoc(day = 13)]
+impl Solution for Day13 {
+    type Input = Vec<(Item, Item)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .split("\n\n")
+            .map(|p| parse_pair(p).map_err(|err| err.rebase(input, p)))
+            .collect()
+    }
+
+    fn part_one(pairs: &Self::Input) -> Answer {
+        parallel::map(pairs.iter().enumerate(), |(i, (a, b))| match a.cmp(b) {
+            Ordering::Less => i + 1,
+            _ => 0,
+        })
+        .into_iter()
+        .sum::<usize>()
+        .into()
+    }
+
+    fn part_two(pairs: &Self::Input) -> Answer {
+        let first = Item::L(vec![Item::L(vec![Item::I(2)])]);
+        let second = Item::L(vec![Item::L(vec![Item::I(6)])]);
+        let packets: Vec<&Item> = pairs
+            .iter()
+            .flat_map(|(a, b)| [a, b])
+            .filter(|i| *i < &second)
+            .collect();
+
+        ((packets.iter().filter(|i| **i < &first).count() + 1) * (packets.len() + 2)).into()
+    }
+}
+
+// nom hands back the input it could not parse, which is where the error points
+fn parse_pair(p: &str) -> Result<(Item, Item)> {
+    let at = |rest: &[u8], message: String| {
+        let start = p.len() - rest.len();
+        let len = p[start..].chars().next().map_or(0, char::len_utf8);
+        Error::at(p, &p[start..start + len], message)
+    };
+    match pair(p.as_bytes()) {
+        Ok((rest, pair)) if rest.iter().all(u8::is_ascii_whitespace) => Ok(pair),
+        Ok((rest, _)) => Err(at(rest, "unexpected text after the packet pair".into())),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            let message = match err.code {
+                nom::error::ErrorKind::TooLarge => {
+                    format!("packets nest lists at most {} deep", MAX_DEPTH)
+                }
+                nom::error::ErrorKind::MapOpt => "packet values go up to 255".into(),
+                _ => "invalid packet".into(),
+            };
+            Err(at(err.input, message))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(at(&[], "the packet pair is cut short".into())),
+    }
+}
+
+#[derive(PartialEq, Debug, Eq)]
+pub enum Item {
+    I(u8),
+    L(Vec<Item>),
+}
+
+// Writes a packet back out as it appears in the input, e.g. [1,[2,3]]
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Item::I(value) => write!(f, "{}", value),
+            Item::L(items) => {
+                write!(f, "[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+// Implementation of an order relation, i.e. how can we say A < B?
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            // here Item::I(a) is such that a is of type u8. Type u8 implements the Ord trait and
+            // as such, one can call `u8::cmp` on a and b.
+            (Item::I(a), Item::I(b)) => u8::cmp(a, b),
+            // here we are in the case where a and b are both lists.
+            // e.g. [1, 2] vs. [5, 7]
+            (Item::L(a), Item::L(b)) => match a.iter().cmp(b) {
+                // here we're matching each iterative comparison's result to two outcomes:
+                // Less or Greater means that the pair is either in the right order or not,
+                // Equal means that
+                result if result != Ordering::Equal => result,
+                _ => a.len().cmp(&b.len()),
+            },
+            (Item::I(_), Item::L(b)) if b.len() == 1 => self.cmp(&b[0]),
+            (Item::I(a), Item::L(_)) => Item::L(vec![Item::I(*a)]).cmp(other),
+            (Item::L(_), Item::I(_)) => other.cmp(self).reverse(),
+        }
+    }
+}
+// Implementing PartialOrd is compulsory for implementing Ord.
+impl PartialOrd for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// named: Makes a function from a parser combination.
+//  // Create a parser that will match either "dragon" or "beast"
+// named!( dragon_or_beast, alt!( tag!( "dragon" ) | tag!( "beast" ) ) );
+
+// Given the input "dragon slayer", the parser will match "dragon"
+// and the rest will be " slayer"
+// let (rest, result) = dragon_or_beast(b"dragon slayer").unwrap();
+// assert_eq!(result, b"dragon");
+// assert_eq!(rest, b" slayer");
+
+// Given the input "beast of Gevaudan", the parser will match "beast"
+// and the rest will be " of Gevaudan"
+// let (rest, result) = dragon_or_beast(&b"beast of Gevaudan"[..]).unwrap();
+// assert_eq!(result, b"beast");
+// assert_eq!(rest, b" of Gevaudan");
+//
+// alt: Tests a list of parsers one by one until one succeeds.
+// first parser: list, computes delimited!(char!('['), separated_list0!(char!(','), item), char!(']')));
+// second parser: num, parses digit1 with atoi::atoi into a u8.
+//
+// map: Maps a function over the result of a parser
+// e.g. map!(num, Item::I) will return Item::I(u8)
+// as num's return type is u8
+named!(value<&[u8], Item>, alt!(map!(list, Item::L) | map!(num, Item::I)));
+
+/// How deep lists may nest in a packet. Parsing, comparing and dropping a
+/// packet all recurse once per level, so this keeps them off the end of the
+/// stack.
+pub const MAX_DEPTH: usize = 64;
+
+/// Parses one packet, e.g. `[1,[2,3]]`, refusing to recurse past
+/// [`MAX_DEPTH`] nested lists.
+pub fn item(input: &[u8]) -> nom::IResult<&[u8], Item> {
+    let mut depth = 0;
+    for (index, &byte) in input.iter().enumerate() {
+        match byte {
+            b'[' if depth == MAX_DEPTH => {
+                return Err(nom::Err::Failure(nom::error::Error::new(
+                    &input[index..],
+                    nom::error::ErrorKind::TooLarge,
+                )))
+            }
+            b'[' => depth += 1,
+            b']' if depth > 0 => depth -= 1,
+            _ => {}
+        }
+        // the end of the packet, or a number rather than a list
+        if depth == 0 {
+            break;
+        }
+    }
+    value(input)
+}
+
+// digit1: Matches one or more digits.
+// atoi: Parses an integer from a slice, or None if it does not fit.
+//
+// A number that does not fit a u8 fails outright rather than letting `alt`
+// backtrack, so the error points at the number.
+fn num(input: &[u8]) -> nom::IResult<&[u8], u8> {
+    let (rest, digits) = nom::character::complete::digit1(input)?;
+    match atoi::atoi(digits) {
+        Some(value) => Ok((rest, value)),
+        None => Err(nom::Err::Failure(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::MapOpt,
+        ))),
+    }
+}
+
+named!(list<&[u8], Vec<Item>>, delimited!(char!('['), separated_list0!(char!(','), value), char!(']')));
+named!(pair<&[u8], (Item, Item)>, separated_pair!(item, tag!("\n"), item));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn integration_test() {
+        let input = Day13::parse(include_str!("../../inputs/examples/day13.txt")).unwrap();
+        assert_eq!(input.len(), 8);
+        assert_eq!(Day13::part_one(&input), Answer::Number(13));
+        assert_eq!(Day13::part_two(&input), Answer::Number(140));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let position = |input: &str| match Day13::parse(input).unwrap_err() {
+            Error::Parse(err) => (err.line, err.column),
+            err => panic!("unexpected error: {}", err),
+        };
+        assert_eq!(position("[1]\n[2]\n\n[3]\n[x]"), (5, 1));
+        assert_eq!(position("[1]\n[2]\n\n[3]\n[4]]"), (5, 4));
+        assert_eq!(position("[1]\n[2]\n\n[3]\n[4"), (5, 3));
+        assert_eq!(position("[1]\n[2]\n\n[3]\n[4,256]"), (5, 4));
+
+        let deep = format!("{}{}", "[".repeat(100), "]".repeat(100));
+        match Day13::parse(&format!("[1]\n{}", deep)).unwrap_err() {
+            Error::Parse(err) => {
+                assert_eq!((err.line, err.column), (2, MAX_DEPTH + 1));
+                assert_eq!(err.message, "packets nest lists at most 64 deep");
+            }
+            err => panic!("unexpected error: {}", err),
+        }
+    }
+
+    fn packet() -> impl Strategy<Value = Item> {
+        any::<u8>()
+            .prop_map(Item::I)
+            .prop_recursive(8, 64, 5, |inner| {
+                prop::collection::vec(inner, 0..5).prop_map(Item::L)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn test_item_round_trip(item in packet()) {
+            let rendered = item.to_string();
+            let (rest, parsed) = super::item(rendered.as_bytes()).unwrap();
+            prop_assert!(rest.is_empty());
+            prop_assert_eq!(parsed, item);
+        }
+
+        #[test]
+        fn test_item_never_panics(input in "[\\[\\],0-9]{0,200}") {
+            let _ = super::item(input.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_parallel() {
+        let inputs = (0..5).map(|seed| generate::generate(seed, &Default::default()).input);
+        parallel::assert_matches_sequential::<Day13>(inputs);
+    }
+}
@@ -0,0 +1,90 @@
+//! Random rock paths below the sand source.
+
+use aoc_common::generate::{Generated, Rng};
+
+use crate::MAX_DEPTH;
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub paths: usize,
+    pub max_corners: usize,
+    pub max_segment: i64,
+    /// How far rock reaches left and right of the source at x=500.
+    pub spread: i64,
+    pub depth: i64,
+}
+
+/// About the size of a real input.
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            paths: 150,
+            max_corners: 6,
+            max_segment: 8,
+            spread: 40,
+            depth: 170,
+        }
+    }
+}
+
+pub fn generate(seed: u64, params: &Params) -> Generated {
+    let (left, right) = (500 - params.spread, 500 + params.spread);
+    assert!(params.max_segment < params.spread.min(params.depth));
+    assert!(
+        params.depth <= MAX_DEPTH as i64,
+        "rock is at most {} deep",
+        MAX_DEPTH
+    );
+    let mut rng = Rng::new(seed);
+    let paths: Vec<String> = (0..params.paths)
+        .map(|_| {
+            let mut x = rng.between(left..=right);
+            let mut y = rng.between(1..=params.depth);
+            let mut horizontal = rng.chance(0.5);
+            let mut corners = vec![format!("{},{}", x, y)];
+            for _ in 1..rng.between(2..=params.max_corners as i64) {
+                // turn at every corner, backing off the edges of the area
+                let length = rng.between(1..=params.max_segment);
+                let (value, low, high) = if horizontal {
+                    (&mut x, left, right)
+                } else {
+                    (&mut y, 1, params.depth)
+                };
+                *value = match (*value + length, *value - length) {
+                    (ahead, _) if ahead <= high && rng.chance(0.5) => ahead,
+                    (_, behind) if behind >= low => behind,
+                    (ahead, _) => ahead,
+                };
+                horizontal = !horizontal;
+                corners.push(format!("{},{}", x, y));
+            }
+            corners.join(" -> ")
+        })
+        .collect();
+    Generated::new(paths.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day14;
+    use aoc_common::{Answer, Part, Solution};
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            generate(1, &Params::default()),
+            generate(1, &Params::default())
+        );
+        for seed in 0..5 {
+            let paths = Day14::parse(&generate(seed, &Params::default()).input).unwrap();
+            let [Answer::Number(abyss), Answer::Number(floor)] =
+                Part::ALL.map(|part| Day14::solve(&paths, part))
+            else {
+                panic!("no sand came to rest for seed {}", seed);
+            };
+            // the floor catches at least what rock alone does
+            assert!(floor >= abyss);
+        }
+    }
+}
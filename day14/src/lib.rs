@@ -0,0 +1,436 @@
+pub mod generate;
+
+use aoc_common::{
+    geometry::{Bounds, Point},
+    parse,
+    simulation::{Simulation, State},
+    Error, Result, Solution,
+};
+use aoc_grid::{Grid, Pos};
+use aoc_macros::{aoc, aoc_example};
+use std::fmt;
+
+pub struct Day14;
+
+#[aoc(day = 14)]
+impl Solution for Day14 {
+    // every rock path as its list of corner coordinates
+    type Input = Vec<Vec<Pos>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+}
+
+#[aoc(day = 14, part = 1)]
+#[aoc_example(expected = 24)]
+fn sand_before_abyss(paths: &[Vec<Pos>]) -> usize {
+    let (cave_topology, _) = fill_cave_topology(paths);
+    Simulation::new(Cave::new(cave_topology, None)).run()
+}
+
+#[aoc(day = 14, part = 2)]
+#[aoc_example(expected = 93)]
+fn sand_until_source_blocked(paths: &[Vec<Pos>]) -> usize {
+    let (cave_topology, floor) = fill_cave_topology(paths);
+    Simulation::new(Cave::new(cave_topology, Some(floor))).run()
+}
+
+/// The deepest rock accepted. The cave is as wide as it is deep, so deeper
+/// rock would only exhaust memory.
+pub const MAX_DEPTH: usize = 1 << 12;
+
+fn parse_input(input: &str) -> Result<Vec<Vec<Pos>>> {
+    input
+        .lines()
+        .map(|line| {
+            let mut path: Vec<Pos> = Vec::new();
+            for element in line.split(" -> ") {
+                let (left, right) = parse::split_once(input, element, ",")?;
+                let corner = Point::new(parse::number(input, left)?, parse::number(input, right)?);
+                if corner.y > MAX_DEPTH {
+                    return Err(Error::at(
+                        input,
+                        right,
+                        format!("rock is at most {} deep", MAX_DEPTH),
+                    ));
+                }
+                // rock paths only run horizontally or vertically
+                if let Some(previous) = path.last() {
+                    if previous.x != corner.x && previous.y != corner.y {
+                        return Err(Error::at(
+                            input,
+                            element,
+                            format!("diagonal path from {}", previous),
+                        ));
+                    }
+                }
+                path.push(corner);
+            }
+            Ok(path)
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tile::Air => write!(f, "."),
+            Tile::Rock => write!(f, "#"),
+            Tile::Sand => write!(f, "o"),
+        }
+    }
+}
+
+// where the sand pours in from
+const SOURCE: Pos = Point::new(500, 0);
+
+// straight down first, then down-left, then down-right
+const FALL: [Point<isize>; 3] = [Point::new(0, 1), Point::new(-1, 1), Point::new(1, 1)];
+
+/// Draws the rock paths into a cave, returning it with the depth of the floor
+/// part two adds two rows below the lowest rock.
+///
+/// Sand spreads at most one column per row it falls, so the cave only spans
+/// the `floor` columns either side of the source: column `floor` of the grid
+/// is the source's x, and rock further out is never reached.
+fn fill_cave_topology(topology_coordinates_vec: &[Vec<Pos>]) -> (Grid<Tile>, usize) {
+    let corners = topology_coordinates_vec.iter().flatten().copied();
+    let bounds = Bounds::of(corners.chain([SOURCE])).expect("the source is in the cave");
+    let floor = bounds
+        .max
+        .y
+        .checked_add(2)
+        .expect("rock is at most MAX_DEPTH deep");
+    // the columns sand can reach
+    let (left, right) = (SOURCE.x.saturating_sub(floor), SOURCE.x + floor);
+
+    let mut cave_topology = Grid::new(2 * floor + 1, floor + 1, Tile::Air);
+    for topology_coordinates in topology_coordinates_vec {
+        for segment in topology_coordinates.windows(2) {
+            // segments are straight, so their bounds are the segment itself
+            let Bounds { min, max } = Bounds::of([segment[0], segment[1]]).unwrap();
+            for x in min.x.max(left)..=max.x.min(right) {
+                for y in min.y..=max.y {
+                    if let Some(position) = in_cave(Point::new(x, y), floor) {
+                        cave_topology[position] = Tile::Rock;
+                    }
+                }
+            }
+        }
+    }
+    (cave_topology, floor)
+}
+
+/// Where a point of the puzzle lands in a cave with the given floor, or
+/// `None` when it is too far left or right of the source for sand to reach.
+fn in_cave(coordinates: Pos, floor: usize) -> Option<Pos> {
+    let x = coordinates.x.checked_add(floor)?.checked_sub(SOURCE.x)?;
+    (x <= 2 * floor).then(|| Point::new(x, coordinates.y))
+}
+
+/// The source's position in the cave, in the middle of its top row.
+fn source(cave_topology: &Grid<Tile>) -> Pos {
+    Point::new(cave_topology.width() / 2, SOURCE.y)
+}
+
+/// Sand poured into the cave, one unit coming to rest per tick, until the
+/// source is blocked or, without a floor, sand falls into the abyss.
+#[derive(Debug, Clone)]
+struct Cave {
+    topology: Grid<Tile>,
+    floor: Option<usize>,
+    // the way the last unit fell from the source, up to where it came to rest;
+    // the next unit follows it as far as it is still open
+    path: Vec<Pos>,
+}
+
+impl Cave {
+    fn new(topology: Grid<Tile>, floor: Option<usize>) -> Self {
+        Cave {
+            topology,
+            floor,
+            path: Vec::new(),
+        }
+    }
+
+    /// Where the next unit of sand comes to rest, or `None` when it falls
+    /// out of the cave. With a `floor`, that row is solid rock.
+    fn settle(&mut self) -> Option<Pos> {
+        if self.path.is_empty() {
+            self.path.push(source(&self.topology));
+        }
+        'falling: loop {
+            let coordinates = *self.path.last().unwrap();
+            for step in FALL {
+                match self.topology.offset(coordinates, step) {
+                    None => return None,
+                    Some(next) if Some(next.y) == self.floor => {}
+                    Some(next) if self.topology[next] == Tile::Air => {
+                        self.path.push(next);
+                        continue 'falling;
+                    }
+                    Some(_) => {}
+                }
+            }
+            return self.path.pop();
+        }
+    }
+
+    /// Pours one unit of sand, returning whether pouring is over: the source
+    /// is blocked, or the unit fell out of the cave.
+    fn pour(&mut self) -> bool {
+        // rock may catch all the sand before any of it reaches the abyss
+        if self.topology[source(&self.topology)] == Tile::Sand {
+            return true;
+        }
+        match self.settle() {
+            Some(rest) => {
+                self.topology[rest] = Tile::Sand;
+                false
+            }
+            None => true,
+        }
+    }
+}
+
+impl State for Cave {
+    fn step(&mut self) -> bool {
+        !self.pour()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use proptest::prelude::*;
+
+    // the part of the cave the example draws
+    fn draw(cave_topology: &Grid<Tile>) -> String {
+        let floor = cave_topology.width() / 2;
+        let left = in_cave(Point::new(494, 0), floor).unwrap().x;
+        cave_topology
+            .rows()
+            .take(10)
+            .map(|row| row[left..left + 10].iter().map(Tile::to_string).collect())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = include_str!("../../inputs/examples/day14.txt");
+        let parsed = parse_input(input).unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                vec![Point::new(498, 4), Point::new(498, 6), Point::new(496, 6)],
+                vec![
+                    Point::new(503, 4),
+                    Point::new(502, 4),
+                    Point::new(502, 9),
+                    Point::new(494, 9)
+                ]
+            ]
+        );
+    }
+
+    fn render_paths(paths: &[Vec<Pos>]) -> String {
+        paths
+            .iter()
+            .map(|path| {
+                let corners: Vec<String> = path
+                    .iter()
+                    .map(|corner| format!("{},{}", corner.x, corner.y))
+                    .collect();
+                corners.join(" -> ") + "\n"
+            })
+            .collect()
+    }
+
+    // a start, then each corner moved along one axis from the previous one
+    fn path() -> impl Strategy<Value = Vec<Pos>> {
+        let turns = prop::collection::vec((any::<bool>(), any::<usize>()), 0..5);
+        ((any::<usize>(), 0..=MAX_DEPTH), turns).prop_map(|((x, y), turns)| {
+            let mut path = vec![Point::new(x, y)];
+            for (horizontal, to) in turns {
+                let previous = path[path.len() - 1];
+                path.push(if horizontal {
+                    Point::new(to, previous.y)
+                } else {
+                    Point::new(previous.x, to % (MAX_DEPTH + 1))
+                });
+            }
+            path
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(paths in prop::collection::vec(path(), 0..5)) {
+            prop_assert_eq!(parse_input(&render_paths(&paths)).unwrap(), paths);
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in "([0-9]{1,3}|,| -> |\n|-){0,20}") {
+            let _ = parse_input(&input);
+        }
+    }
+
+    #[test]
+    fn test_fill_cave_topology() {
+        let input = include_str!("../../inputs/examples/day14.txt");
+        let parsed = parse_input(input).unwrap();
+        let (cave_topology, floor) = fill_cave_topology(&parsed);
+        assert_eq!(floor, 11);
+        let rocks: HashSet<Pos> = cave_topology
+            .iter()
+            .filter(|(_, &tile)| tile == Tile::Rock)
+            .map(|(position, _)| Point::new(position.x + SOURCE.x - floor, position.y))
+            .collect();
+        assert_eq!(
+            rocks,
+            HashSet::from([
+                Point::new(498, 4),
+                Point::new(498, 5),
+                Point::new(498, 6),
+                Point::new(497, 6),
+                Point::new(496, 6),
+                Point::new(503, 4),
+                Point::new(502, 4),
+                Point::new(502, 5),
+                Point::new(502, 6),
+                Point::new(502, 7),
+                Point::new(502, 8),
+                Point::new(502, 9),
+                Point::new(501, 9),
+                Point::new(500, 9),
+                Point::new(499, 9),
+                Point::new(498, 9),
+                Point::new(497, 9),
+                Point::new(496, 9),
+                Point::new(495, 9),
+                Point::new(494, 9)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_sand_fall() {
+        let input = include_str!("../../inputs/examples/day14.txt");
+        let parsed = parse_input(input).unwrap();
+        let (cave_topology, floor) = fill_cave_topology(&parsed);
+        let below_source = in_cave(Point::new(500, 8), floor).unwrap();
+        let mut cave = Cave::new(cave_topology, None);
+        assert_eq!(cave.topology[below_source], Tile::Air);
+        cave.pour();
+        assert_eq!(cave.topology[below_source], Tile::Sand);
+    }
+
+    #[test]
+    fn test_draw() {
+        let input = include_str!("../../inputs/examples/day14.txt");
+        let parsed = parse_input(input).unwrap();
+        let (cave_topology, _) = fill_cave_topology(&parsed);
+        let mut cave = Cave::new(cave_topology, None);
+        while !cave.pour() {}
+        assert_eq!(
+            draw(&cave.topology),
+            "..........\n\
+             ..........\n\
+             ......o...\n\
+             .....ooo..\n\
+             ....#ooo##\n\
+             ...o#ooo#.\n\
+             ..###ooo#.\n\
+             ....oooo#.\n\
+             .o.ooooo#.\n\
+             #########."
+        );
+    }
+
+    #[test]
+    fn test_sand_fall_blocked() {
+        // a bowl under the source holds all the sand, none reaches the abyss
+        let parsed = parse_input("498,1 -> 498,3 -> 502,3 -> 502,1").unwrap();
+        let (cave_topology, _) = fill_cave_topology(&parsed);
+        assert_eq!(Simulation::new(Cave::new(cave_topology, None)).run(), 7);
+    }
+
+    #[test]
+    fn test_deep_floor() {
+        // the pile on the floor spreads further left than x=0
+        let parsed = parse_input("500,600 -> 501,600").unwrap();
+        assert_eq!(sand_before_abyss(&parsed), 0);
+        // every point in reach of the source, bar the two rocks
+        assert_eq!(sand_until_source_blocked(&parsed), 602 * 602 - 2);
+    }
+
+    #[test]
+    fn test_far_rock_is_left_out() {
+        // rock no sand can reach does not widen the cave
+        let near = parse_input("498,6 -> 502,6").unwrap();
+        let parsed = parse_input("100000,5 -> 100000,6\n498,6 -> 502,6").unwrap();
+        let (cave_topology, floor) = fill_cave_topology(&parsed);
+        assert_eq!(cave_topology.width(), 2 * floor + 1);
+        assert_eq!(sand_before_abyss(&parsed), sand_before_abyss(&near));
+        assert_eq!(
+            sand_until_source_blocked(&parsed),
+            sand_until_source_blocked(&near)
+        );
+    }
+
+    #[test]
+    fn test_extremes() {
+        // rock as far left and right as a path can go, filling the cave's row
+        let parsed = parse_input(&format!("0,5 -> {},5", usize::MAX)).unwrap();
+        assert_eq!(sand_before_abyss(&parsed), 25);
+        assert_eq!(sand_until_source_blocked(&parsed), 25);
+        let parsed = parse_input(&format!("{0},0 -> {0},{1}", usize::MAX, MAX_DEPTH)).unwrap();
+        assert_eq!(sand_before_abyss(&parsed), 0);
+
+        match parse_input(&format!("500,2 -> 500,{}", MAX_DEPTH + 1)).unwrap_err() {
+            Error::Parse(err) => {
+                assert_eq!((err.line, err.column), (1, 14));
+                assert_eq!(err.message, "rock is at most 4096 deep");
+            }
+            err => panic!("unexpected error: {}", err),
+        }
+        assert!(parse_input(&format!("500,{}0", usize::MAX)).is_err());
+    }
+
+    #[test]
+    fn test_rewind() {
+        let input = include_str!("../../inputs/examples/day14.txt");
+        let parsed = parse_input(input).unwrap();
+        let (cave_topology, _) = fill_cave_topology(&parsed);
+        let mut simulation = Simulation::new(Cave::new(cave_topology, None)).snapshot_every(10);
+        assert_eq!(simulation.run(), 24);
+
+        // the first five units, as drawn in the puzzle
+        simulation.rewind(5);
+        assert_eq!(
+            draw(&simulation.state().topology),
+            "..........\n\
+             ..........\n\
+             ..........\n\
+             ..........\n\
+             ....#...##\n\
+             ....#...#.\n\
+             ..###...#.\n\
+             ......o.#.\n\
+             ....oooo#.\n\
+             #########."
+        );
+        assert_eq!(simulation.run(), 19);
+    }
+}
@@ -0,0 +1,111 @@
+//! Random sensor reports.
+//!
+//! Every sensor's beacon is strictly closer to it than any other beacon, as
+//! the puzzle promises.
+
+use aoc_common::{
+    generate::{Generated, Rng},
+    geometry::Point,
+};
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub sensors: usize,
+    /// Sensors are placed at coordinates from 0 up to this.
+    pub area: i32,
+    pub max_distance: i32,
+}
+
+/// About the size of a real input.
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            sensors: 40,
+            area: 4_000_000,
+            max_distance: 1_000_000,
+        }
+    }
+}
+
+pub fn generate(seed: u64, params: &Params) -> Generated {
+    let mut rng = Rng::new(seed);
+    let mut reports: Vec<(Point<i32>, Point<i32>)> = Vec::with_capacity(params.sensors);
+    while reports.len() < params.sensors {
+        let coordinate = |rng: &mut Rng| rng.between(0..=i64::from(params.area)) as i32;
+        let sensor = Point::new(coordinate(&mut rng), coordinate(&mut rng));
+        let distance = rng.between(1..=i64::from(params.max_distance)) as i32;
+        let across = rng.between(0..=i64::from(distance)) as i32;
+        let sign = |rng: &mut Rng| if rng.chance(0.5) { 1 } else { -1 };
+        let beacon = sensor
+            + Point::new(
+                sign(&mut rng) * across,
+                sign(&mut rng) * (distance - across),
+            );
+
+        // draw again unless every sensor keeps its beacon the closest
+        let clear = reports.iter().all(|&(other, other_beacon)| {
+            other != sensor
+                && sensor.manhattan(other_beacon) > distance
+                && other.manhattan(beacon) > other.manhattan(other_beacon)
+        });
+        if clear {
+            reports.push((sensor, beacon));
+        }
+    }
+
+    let lines: Vec<String> = reports
+        .iter()
+        .map(|(sensor, beacon)| {
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                sensor.x, sensor.y, beacon.x, beacon.y
+            )
+        })
+        .collect();
+    Generated::new(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day15, GridElement};
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            generate(1, &Params::default()),
+            generate(1, &Params::default())
+        );
+        for seed in 0..20 {
+            let elements = Day15::parse(&generate(seed, &Params::default()).input)
+                .unwrap()
+                .grid;
+            let reports: Vec<(Point<i32>, Point<i32>)> = elements
+                .iter()
+                .filter_map(|element| match element {
+                    GridElement::Sensor(report) => Some(*report),
+                    GridElement::Beacon(_) => None,
+                })
+                .collect();
+            assert_eq!(reports.len(), 40);
+            for (sensor, beacon) in &reports {
+                let closest = reports
+                    .iter()
+                    .map(|(_, other)| sensor.manhattan(*other))
+                    .min();
+                assert_eq!(closest, Some(sensor.manhattan(*beacon)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_large() {
+        let large = Params {
+            sensors: 2000,
+            ..Params::default()
+        };
+        let readings = Day15::parse(&generate(0, &large).input).unwrap();
+        assert_eq!(readings.grid.len(), 4000);
+    }
+}
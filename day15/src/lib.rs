@@ -0,0 +1,227 @@
+pub mod generate;
+
+use aoc_common::{geometry::Point, parse, Answer, Part, Result, Solution};
+use aoc_macros::aoc;
+use std::collections::HashSet;
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub enum GridElement {
+    Beacon(Point<i32>),
+    // Sensor is a tuple for sensor_coordinates, nearest_beacon
+    Sensor((Point<i32>, Point<i32>)),
+}
+
+/// The row part one asks about in the real inputs.
+pub const ROW: i32 = 2_000_000;
+
+/// The row the puzzle's example asks about instead.
+pub const EXAMPLE_ROW: i32 = 10;
+
+/// The sensor reports, and the row part one counts positions on, which the
+/// reports do not say: [`ROW`], or [`EXAMPLE_ROW`] for the example.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Readings {
+    pub row: i32,
+    pub grid: HashSet<GridElement>,
+}
+
+/// Solves part one from the stretch of the row each sensor's range covers,
+/// without visiting the positions themselves.
+pub struct Day15;
+
+#[aoc(day = 15)]
+impl Solution for Day15 {
+    const PARTS: &'static [Part] = &[Part::One];
+
+    type Input = Readings;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input, ROW)
+    }
+
+    fn parse_example(input: &str) -> Result<Self::Input> {
+        parse_input(input, EXAMPLE_ROW)
+    }
+
+    fn part_one(readings: &Self::Input) -> Answer {
+        let covered: i64 = merge(row_coverage(readings))
+            .into_iter()
+            .map(|(start, end)| end - start + 1)
+            .sum();
+        // a beacon on the row is where a beacon can be; each is in the range
+        // of the sensor that reports it, so it was counted
+        let beacons = readings
+            .grid
+            .iter()
+            .filter(|element| {
+                matches!(element, GridElement::Beacon(beacon) if beacon.y == readings.row)
+            })
+            .count() as i64;
+        (covered - beacons).into()
+    }
+}
+
+// The x coordinates, first to last, of the row that each sensor's range
+// covers, in i64 as ranges of i32 coordinates may not fit in an i32
+fn row_coverage(readings: &Readings) -> Vec<(i64, i64)> {
+    let row = i64::from(readings.row);
+    readings
+        .grid
+        .iter()
+        .filter_map(|element| match element {
+            GridElement::Sensor((sensor, beacon)) => Some((sensor.cast(), beacon.cast())),
+            GridElement::Beacon(_) => None,
+        })
+        .filter_map(|(sensor, beacon): (Point<i64>, Point<i64>)| {
+            let reach = sensor.manhattan(beacon) - (sensor.y - row).abs();
+            (reach >= 0).then_some((sensor.x - reach, sensor.x + reach))
+        })
+        .collect()
+}
+
+// The same positions as `ranges`, as ranges that neither overlap nor touch,
+// in order
+fn merge(mut ranges: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+    ranges.sort_unstable();
+    let mut merged: Vec<(i64, i64)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 + 1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+fn parse_input(input: &str, row: i32) -> Result<Readings> {
+    let mut result = HashSet::new();
+    for line in input.lines() {
+        let (sensor_part, beacon_part) = parse::split_once(input, line, ":")?;
+        let sensor = parse_point(
+            input,
+            parse::strip_prefix(input, sensor_part, "Sensor at ")?,
+        )?;
+        let beacon = parse_point(
+            input,
+            parse::strip_prefix(input, beacon_part, " closest beacon is at ")?,
+        )?;
+        result.insert(GridElement::Beacon(beacon));
+        result.insert(GridElement::Sensor((sensor, beacon)));
+    }
+    Ok(Readings { row, grid: result })
+}
+
+// e.g. "x=2, y=18"
+fn parse_point(input: &str, text: &str) -> Result<Point<i32>> {
+    let (x, y) = parse::split_once(input, text, ", ")?;
+    Ok(Point::new(
+        parse::number(input, parse::strip_prefix(input, x, "x=")?)?,
+        parse::number(input, parse::strip_prefix(input, y, "y=")?)?,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn point() -> impl Strategy<Value = Point<i32>> {
+        (any::<i32>(), any::<i32>()).prop_map(|(x, y)| Point::new(x, y))
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(reports in prop::collection::vec((point(), point()), 0..10)) {
+            let input: String = reports
+                .iter()
+                .map(|(sensor, beacon)| {
+                    format!(
+                        "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                        sensor.x, sensor.y, beacon.x, beacon.y
+                    )
+                })
+                .collect();
+            let expected: HashSet<GridElement> = reports
+                .iter()
+                .flat_map(|&(sensor, beacon)| {
+                    [GridElement::Sensor((sensor, beacon)), GridElement::Beacon(beacon)]
+                })
+                .collect();
+            prop_assert_eq!(
+                parse_input(&input, ROW).unwrap(),
+                Readings { row: ROW, grid: expected }
+            );
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in "(Sensor at |closest beacon is at |x=|y=|-?[0-9]{1,12}|, |: |\n){0,16}") {
+            let _ = parse_input(&input, ROW);
+        }
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = include_str!("../../inputs/examples/day15.txt");
+        let Readings { row, grid } = Day15::parse_example(input).unwrap();
+        assert_eq!(row, EXAMPLE_ROW);
+        // checking a couple of elements exist in the hashset
+        let sensor = GridElement::Sensor((Point::new(13, 2), Point::new(15, 3)));
+        assert!(grid.contains(&sensor));
+
+        let beacon = GridElement::Beacon(Point::new(25, 17));
+        assert!(grid.contains(&beacon));
+
+        let sensor_none = GridElement::Sensor((Point::new(1, 1), Point::new(0, 0)));
+        assert!(!grid.contains(&sensor_none));
+    }
+
+    #[test]
+    fn test_row_coverage() {
+        let input = include_str!("../../inputs/examples/day15.txt");
+        let readings = Day15::parse_example(input).unwrap();
+        // the sensor at 8,7 reaches 9 away, so 6 into row 10 either side
+        assert!(row_coverage(&readings).contains(&(2, 14)));
+        assert_eq!(merge(row_coverage(&readings)), [(-2, 24)]);
+
+        assert_eq!(
+            merge(vec![(5, 6), (0, 2), (3, 3), (1, 1)]),
+            [(0, 3), (5, 6)]
+        );
+        assert_eq!(merge(Vec::new()), []);
+    }
+
+    #[test]
+    fn test_extremes() {
+        // ranges wider than an i32 holds
+        let report = format!(
+            "Sensor at x={0}, y={0}: closest beacon is at x={1}, y={1}",
+            i32::MIN,
+            i32::MAX
+        );
+        let width = 2 * (2 * (i64::from(i32::MAX) - i64::from(i32::MIN))) + 1;
+        let answer = Day15::part_one(&parse_input(&report, i32::MIN).unwrap());
+        assert_eq!(answer, Answer::Number(i128::from(width)));
+    }
+
+    #[test]
+    fn test_integration() {
+        let input = include_str!("../../inputs/examples/day15.txt");
+        assert_eq!(
+            Day15::part_one(&Day15::parse_example(input).unwrap()),
+            Answer::Number(26)
+        );
+    }
+
+    #[test]
+    fn test_row() {
+        // the row is a y coordinate, and a beacon on it is no place without one
+        let report = "Sensor at x=0, y=0: closest beacon is at x=0, y=10";
+        let answer = |row| Day15::part_one(&parse_input(report, row).unwrap());
+        assert_eq!(answer(10), Answer::Number(0));
+        assert_eq!(answer(5), Answer::Number(11));
+        assert_eq!(answer(0), Answer::Number(21));
+        assert_eq!(answer(-10), Answer::Number(1));
+        assert_eq!(answer(11), Answer::Number(0));
+        assert_eq!(answer(ROW), Answer::Number(0));
+    }
+}
@@ -0,0 +1,64 @@
+//! Random strategy guides, with both answers.
+
+use aoc_common::generate::{Generated, Rng};
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub rounds: usize,
+}
+
+/// About the size of a real input.
+impl Default for Params {
+    fn default() -> Self {
+        Params { rounds: 2500 }
+    }
+}
+
+pub fn generate(seed: u64, params: &Params) -> Generated {
+    let mut rng = Rng::new(seed);
+    let (mut part_one, mut part_two) = (0, 0);
+    let rounds: Vec<String> = (0..params.rounds)
+        .map(|_| {
+            // rock, paper and scissors are 0, 1 and 2, each beating the one before
+            let (opponent, column) = (rng.below(3), rng.below(3));
+
+            // part one reads the column as a shape...
+            let outcome = (column + 4 - opponent) % 3;
+            part_one += column + 1 + 3 * outcome;
+            // ...and part two as lose, draw or win
+            let shape = (opponent + column + 2) % 3;
+            part_two += shape + 1 + 3 * column;
+
+            format!("{} {}", ['A', 'B', 'C'][opponent], ['X', 'Y', 'Z'][column])
+        })
+        .collect();
+
+    Generated::new(rounds.join("\n")).with_answers(part_one, part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day2;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            generate(1, &Params::default()),
+            generate(1, &Params::default())
+        );
+        for seed in 0..20 {
+            generate(seed, &Params::default()).assert_solved_by::<Day2>();
+        }
+        // the example guide, one round at a time
+        let scores = [("A Y", 8, 4), ("B X", 1, 1), ("C Z", 6, 7)];
+        for (round, part_one, part_two) in scores {
+            let seed = (0..)
+                .find(|&seed| generate(seed, &Params { rounds: 1 }).input == round)
+                .unwrap();
+            let generated = generate(seed, &Params { rounds: 1 });
+            assert_eq!(generated.part_one, Some(part_one.into()));
+            assert_eq!(generated.part_two, Some(part_two.into()));
+        }
+    }
+}
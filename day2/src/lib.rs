@@ -0,0 +1,325 @@
+pub mod generate;
+pub mod rules;
+
+use aoc_common::{Answer, Error, Result, Solution};
+use aoc_macros::aoc;
+use std::{fmt, str::FromStr};
+
+pub use rules::{Line, Played, Rules, ShapeRule};
+
+pub struct Day2;
+
+/// Played by the [standard rules](Rules::standard).
+#[aoc(day = 2)]
+impl Solution for Day2 {
+    // every round of the strategy guide, as written
+    type Input = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| line.parse().map_err(|err: Error| err.rebase(input, line)))
+            .collect()
+    }
+
+    fn part_one(rounds: &Self::Input) -> Answer {
+        total_score(rounds, Interpretation::Shape).into()
+    }
+
+    fn part_two(rounds: &Self::Input) -> Answer {
+        total_score(rounds, Interpretation::Outcome).into()
+    }
+
+    fn breakdown(rounds: &Self::Input) -> Option<String> {
+        let lines: Vec<Line> = rounds.iter().map(|&round| Line::from(round)).collect();
+        Some(Rules::standard().render_breakdown(&lines))
+    }
+}
+
+/// The shapes of the [standard rules](Rules::standard), which are the source
+/// of everything known about them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+/// How a round ends for the one playing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+/// One line of the strategy guide by the standard rules, e.g. `A Y`, with the
+/// second column read both ways. Other rules read guides as [`Line`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Round {
+    pub opponent: Shape,
+    /// The shape the second column stands for, e.g. Y for paper.
+    pub shape: Shape,
+    /// The outcome it asks for, e.g. Y for a draw.
+    pub outcome: Outcome,
+}
+
+/// What the second column of the guide asks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interpretation {
+    /// The shape to play, e.g. X for rock (part one).
+    Shape,
+    /// How the round should end, e.g. X for a loss (part two).
+    Outcome,
+}
+
+impl Shape {
+    /// In the order the standard rules list them.
+    pub const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    /// Its index into the standard rules' [shapes](Rules::shapes).
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn from_index(index: usize) -> Option<Shape> {
+        Shape::ALL.get(index).copied()
+    }
+
+    /// The shape this one defeats.
+    pub fn beats(self) -> Shape {
+        Outcome::Lose.against(self)
+    }
+
+    /// The shape that defeats this one.
+    pub fn loses_to(self) -> Shape {
+        Outcome::Win.against(self)
+    }
+
+    /// How playing this shape against `opponent` ends.
+    pub fn against(self, opponent: Shape) -> Outcome {
+        Rules::standard().outcome(self.index(), opponent.index())
+    }
+
+    pub fn score(self) -> u64 {
+        self.rule().score
+    }
+
+    fn rule(self) -> &'static ShapeRule {
+        &Rules::standard().shapes()[self.index()]
+    }
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+    /// The shape to play against `opponent` for the round to end this way.
+    pub fn against(self, opponent: Shape) -> Shape {
+        Rules::standard()
+            .response(self, opponent.index())
+            .and_then(Shape::from_index)
+            .expect("every outcome is possible by the standard rules")
+    }
+
+    pub fn score(self) -> u64 {
+        Rules::standard().points().of(self)
+    }
+}
+
+impl Round {
+    /// The shape played and how the round ends, as `interpretation` reads the
+    /// second column.
+    pub fn play(self, interpretation: Interpretation) -> (Shape, Outcome) {
+        match interpretation {
+            Interpretation::Shape => (self.shape, self.shape.against(self.opponent)),
+            Interpretation::Outcome => (self.outcome.against(self.opponent), self.outcome),
+        }
+    }
+
+    pub fn score(self, interpretation: Interpretation) -> u64 {
+        let (shape, outcome) = self.play(interpretation);
+        shape.score() + outcome.score()
+    }
+}
+
+/// The opponent's letter, then the response's, separated by whitespace.
+impl FromStr for Round {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self> {
+        let line = Rules::standard().parse_line(line)?;
+        // the standard rules read every letter of the second column both ways
+        let shape = |index: usize| Shape::from_index(index).expect("a standard shape");
+        Ok(Round {
+            opponent: shape(line.opponent),
+            shape: shape(line.shape.expect("X, Y and Z are shapes")),
+            outcome: line.outcome.expect("X, Y and Z are outcomes"),
+        })
+    }
+}
+
+/// The round as the standard rules number their shapes, to be played by
+/// those rules.
+impl From<Round> for Line {
+    fn from(round: Round) -> Self {
+        Line {
+            opponent: round.opponent.index(),
+            shape: Some(round.shape.index()),
+            outcome: Some(round.outcome),
+        }
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.rule().name)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Outcome::Lose => "lose",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+pub fn total_score(rounds: &[Round], interpretation: Interpretation) -> u64 {
+    rounds.iter().map(|round| round.score(interpretation)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Vec<Round> {
+        Day2::parse(include_str!("../../inputs/examples/day02.txt")).unwrap()
+    }
+
+    #[test]
+    fn test_shapes() {
+        for shape in Shape::ALL {
+            assert_eq!(shape.beats().loses_to(), shape);
+            assert_eq!(shape.loses_to().beats(), shape);
+            assert_eq!(shape.against(shape), Outcome::Draw);
+            assert_eq!(shape.against(shape.beats()), Outcome::Win);
+            assert_eq!(shape.against(shape.loses_to()), Outcome::Lose);
+            for outcome in Outcome::ALL {
+                assert_eq!(outcome.against(shape).against(shape), outcome);
+            }
+        }
+        assert_eq!(Shape::Rock.beats(), Shape::Scissors);
+        assert_eq!(Shape::Rock.loses_to(), Shape::Paper);
+        assert_eq!(Shape::Paper.beats(), Shape::Rock);
+        assert_eq!(Outcome::Win.against(Shape::Scissors), Shape::Rock);
+        assert_eq!(Shape::Scissors.score(), 3);
+        assert_eq!(Shape::Paper.to_string(), "paper");
+        assert_eq!(Shape::from_index(3), None);
+        assert_eq!(Outcome::Draw.score(), 3);
+
+        // a typed round plays as its line does by the standard rules
+        for opponent in ["A", "B", "C"] {
+            for response in ["X", "Y", "Z"] {
+                let round: Round = format!("{} {}", opponent, response).parse().unwrap();
+                for interpretation in [Interpretation::Shape, Interpretation::Outcome] {
+                    let played = Rules::standard()
+                        .play(Line::from(round), interpretation)
+                        .unwrap();
+                    let (shape, outcome) = round.play(interpretation);
+                    assert_eq!(played.shape.name, shape.to_string());
+                    assert_eq!(played.outcome, outcome);
+                    assert_eq!(played.score(), round.score(interpretation));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        // X, Y and Z stand for a shape and an outcome both
+        let round = |opponent, shape, outcome| Round {
+            opponent,
+            shape,
+            outcome,
+        };
+        assert_eq!(
+            example(),
+            [
+                round(Shape::Rock, Shape::Paper, Outcome::Draw),
+                round(Shape::Paper, Shape::Rock, Outcome::Lose),
+                round(Shape::Scissors, Shape::Scissors, Outcome::Win),
+            ]
+        );
+        assert_eq!("C  Z".parse::<Round>().unwrap(), example()[2]);
+
+        let error = |input: &str| match Day2::parse(input).unwrap_err() {
+            Error::Parse(err) => (err.line, err.column, err.message),
+            err => panic!("unexpected error: {}", err),
+        };
+        assert_eq!(error("A Y\nD X"), (2, 1, "expected A, B or C".to_string()));
+        assert_eq!(error("A Y\nB  W"), (2, 4, "expected X, Y or Z".to_string()));
+        assert_eq!(
+            error("A Y\n\nC Z"),
+            (2, 1, "round should have two columns".to_string())
+        );
+        assert_eq!(error("A Y Z").2, "round should have two columns");
+    }
+
+    #[test]
+    fn test_example() {
+        let rounds = example();
+        assert_eq!(Day2::part_one(&rounds), Answer::Number(15));
+        assert_eq!(Day2::part_two(&rounds), Answer::Number(12));
+
+        assert_eq!(
+            rounds[0].play(Interpretation::Shape),
+            (Shape::Paper, Outcome::Win)
+        );
+        assert_eq!(rounds[0].score(Interpretation::Outcome), 4);
+
+        let lines: Vec<Line> = rounds.iter().map(|&round| Line::from(round)).collect();
+        let as_shapes: Vec<String> = Rules::standard()
+            .breakdown(&lines, Interpretation::Shape)
+            .unwrap()
+            .iter()
+            .map(Played::to_string)
+            .collect();
+        assert_eq!(
+            as_shapes,
+            [
+                "paper vs rock: win, 2 + 6 = 8",
+                "rock vs paper: lose, 1 + 0 = 1",
+                "scissors vs scissors: draw, 3 + 3 = 6",
+            ]
+        );
+        let as_outcomes: Vec<String> = Rules::standard()
+            .breakdown(&lines, Interpretation::Outcome)
+            .unwrap()
+            .iter()
+            .map(Played::to_string)
+            .collect();
+        assert_eq!(
+            as_outcomes,
+            [
+                "rock vs rock: draw, 1 + 3 = 4",
+                "rock vs paper: lose, 1 + 0 = 1",
+                "rock vs scissors: win, 1 + 6 = 7",
+            ]
+        );
+        assert_eq!(
+            Day2::breakdown(&rounds).unwrap(),
+            "second column as shapes:\n\
+             \x20   1  paper vs rock: win, 2 + 6 = 8\n\
+             \x20   2  rock vs paper: lose, 1 + 0 = 1\n\
+             \x20   3  scissors vs scissors: draw, 3 + 3 = 6\n\
+             \n\
+             second column as outcomes:\n\
+             \x20   1  rock vs rock: draw, 1 + 3 = 4\n\
+             \x20   2  rock vs paper: lose, 1 + 0 = 1\n\
+             \x20   3  rock vs scissors: win, 1 + 6 = 7\n"
+        );
+    }
+}
@@ -0,0 +1,728 @@
+//! The rules a strategy guide is played by: the shapes, who beats whom and
+//! how rounds score, read from a small config file like `rules/rpsls.txt`.
+//! Guides are read by these rules as [`Line`]s, and typed [`Round`]s of the
+//! standard rules become lines with [`Rules::line`].
+
+use std::{cmp::Reverse, fmt, fs, path::Path, str::FromStr, sync::OnceLock};
+
+use aoc_common::{Error, Result};
+
+use crate::{Interpretation, Outcome, Round, Shape};
+
+/// Rock paper scissors, the rules of the puzzle.
+pub const RPS: &str = include_str!("../rules/rps.txt");
+/// Rock paper scissors lizard Spock.
+pub const RPSLS: &str = include_str!("../rules/rpsls.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    shapes: Vec<ShapeRule>,
+    // beats[a][b] when shape a beats shape b
+    beats: Vec<Vec<bool>>,
+    points: Points,
+    // the response letters asking to lose, draw and win
+    outcomes: [String; 3],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShapeRule {
+    pub name: String,
+    pub score: u64,
+    /// What the first column of the guide calls it.
+    pub opponent: String,
+    /// What the second column calls it, read as a shape.
+    pub response: String,
+}
+
+/// What a round is worth by how it ends, on top of the shape's score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Points {
+    pub loss: u64,
+    pub draw: u64,
+    pub win: u64,
+}
+
+/// One line of a strategy guide as some rules read it, with the second
+/// column read both ways. [`Round`](crate::Round) is the same by the standard
+/// rules, with typed shapes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line {
+    /// Index into [`Rules::shapes`].
+    pub opponent: usize,
+    /// The shape the second column stands for, if any.
+    pub shape: Option<usize>,
+    /// The outcome it asks for, if any.
+    pub outcome: Option<Outcome>,
+}
+
+/// A round once played: the shape chosen for it and how it went.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Played<'r> {
+    pub line: Line,
+    pub opponent: &'r ShapeRule,
+    pub shape: &'r ShapeRule,
+    pub outcome: Outcome,
+    points: u64,
+}
+
+impl Default for Points {
+    fn default() -> Self {
+        Points {
+            loss: 0,
+            draw: 3,
+            win: 6,
+        }
+    }
+}
+
+impl Points {
+    pub fn of(self, outcome: Outcome) -> u64 {
+        match outcome {
+            Outcome::Lose => self.loss,
+            Outcome::Draw => self.draw,
+            Outcome::Win => self.win,
+        }
+    }
+}
+
+/// The standard rules, [`RPS`].
+impl Default for Rules {
+    fn default() -> Self {
+        Rules::standard().clone()
+    }
+}
+
+impl Rules {
+    /// [`RPS`], parsed once.
+    pub fn standard() -> &'static Rules {
+        static STANDARD: OnceLock<Rules> = OnceLock::new();
+        STANDARD.get_or_init(|| RPS.parse().expect("the built-in rules are valid"))
+    }
+
+    pub fn rpsls() -> Rules {
+        RPSLS.parse().expect("the built-in rules are valid")
+    }
+
+    /// Reads the rules from a config file, see [`RPS`] for the format.
+    pub fn load(path: &Path) -> Result<Rules> {
+        let config = fs::read_to_string(path).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        config.parse()
+    }
+
+    pub fn shapes(&self) -> &[ShapeRule] {
+        &self.shapes
+    }
+
+    pub fn points(&self) -> Points {
+        self.points
+    }
+
+    /// Whether `shape` beats `other`, both indices into
+    /// [`shapes`](Self::shapes).
+    pub fn beats(&self, shape: usize, other: usize) -> bool {
+        self.beats[shape][other]
+    }
+
+    /// How playing `shape` against `opponent` ends. Shapes neither of which
+    /// beats the other draw.
+    pub fn outcome(&self, shape: usize, opponent: usize) -> Outcome {
+        if self.beats(shape, opponent) {
+            Outcome::Win
+        } else if self.beats(opponent, shape) {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// The shape to play against `opponent` for the round to end as
+    /// `outcome`: the best scoring of those that do, the first listed of them
+    /// on a tie.
+    pub fn response(&self, outcome: Outcome, opponent: usize) -> Option<usize> {
+        (0..self.shapes.len())
+            .filter(|&shape| self.outcome(shape, opponent) == outcome)
+            .max_by_key(|&shape| (self.shapes[shape].score, Reverse(shape)))
+    }
+
+    /// The opponent's letter, then the response's, separated by whitespace.
+    pub fn parse_line(&self, line: &str) -> Result<Line> {
+        let [opponent, response] = line.split_whitespace().collect::<Vec<&str>>()[..] else {
+            return Err(Error::at(line, line, "round should have two columns"));
+        };
+        let Some(opponent) = self.shapes.iter().position(|s| s.opponent == opponent) else {
+            let letters = self.shapes.iter().map(|s| s.opponent.as_str());
+            return Err(Error::at(line, opponent, expected(letters)));
+        };
+        let shape = self.shapes.iter().position(|s| s.response == response);
+        let outcome = self
+            .outcomes
+            .iter()
+            .position(|letter| letter == response)
+            .map(|index| Outcome::ALL[index]);
+        if shape.is_none() && outcome.is_none() {
+            let mut letters: Vec<&str> = self.shapes.iter().map(|s| s.response.as_str()).collect();
+            for letter in &self.outcomes {
+                if !letters.contains(&letter.as_str()) {
+                    letters.push(letter);
+                }
+            }
+            return Err(Error::at(line, response, expected(letters)));
+        }
+        Ok(Line {
+            opponent,
+            shape,
+            outcome,
+        })
+    }
+
+    /// `round` with its shapes looked up by name among these rules', `None`
+    /// if they lack the opponent's shape. A response shape they lack leaves
+    /// only the outcome to play.
+    pub fn line(&self, round: Round) -> Option<Line> {
+        let find = |shape: Shape| {
+            let name = shape.to_string();
+            self.shapes.iter().position(|rule| rule.name == name)
+        };
+        Some(Line {
+            opponent: find(round.opponent)?,
+            shape: find(round.shape),
+            outcome: Some(round.outcome),
+        })
+    }
+
+    /// A strategy guide, one round per line.
+    pub fn parse_guide(&self, input: &str) -> Result<Vec<Line>> {
+        input
+            .lines()
+            .map(|line| self.parse_line(line).map_err(|err| err.rebase(input, line)))
+            .collect()
+    }
+
+    /// Plays `line` as `interpretation` reads it, `None` if its second
+    /// column means nothing that way, or no shape ends it as asked. A line
+    /// parsed by other rules may name shapes these rules do not have; it is
+    /// `None` too.
+    pub fn play(&self, line: Line, interpretation: Interpretation) -> Option<Played<'_>> {
+        let opponent = self.shapes.get(line.opponent)?;
+        let (shape, outcome) = match interpretation {
+            Interpretation::Shape => {
+                let shape = line.shape.filter(|&shape| shape < self.shapes.len())?;
+                (shape, self.outcome(shape, line.opponent))
+            }
+            Interpretation::Outcome => {
+                let outcome = line.outcome?;
+                (self.response(outcome, line.opponent)?, outcome)
+            }
+        };
+        Some(Played {
+            line,
+            opponent,
+            shape: &self.shapes[shape],
+            outcome,
+            points: self.points.of(outcome),
+        })
+    }
+
+    /// Every line played as `interpretation` reads the guide, `None` if one
+    /// of them cannot be.
+    pub fn breakdown(
+        &self,
+        lines: &[Line],
+        interpretation: Interpretation,
+    ) -> Option<Vec<Played<'_>>> {
+        lines
+            .iter()
+            .map(|&line| self.play(line, interpretation))
+            .collect()
+    }
+
+    /// How every line scores under each reading of the guide, e.g.
+    /// `    1  paper vs rock: win, 2 + 6 = 8`.
+    pub fn render_breakdown(&self, lines: &[Line]) -> String {
+        let readings = [
+            ("second column as shapes", Interpretation::Shape),
+            ("second column as outcomes", Interpretation::Outcome),
+        ];
+        let mut text = String::new();
+        for (title, interpretation) in readings {
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(&format!("{}:\n", title));
+            for (index, &line) in lines.iter().enumerate() {
+                match self.play(line, interpretation) {
+                    Some(played) => text.push_str(&format!("{:>5}  {}\n", index + 1, played)),
+                    None => {
+                        text.push_str(&format!("{:>5}  cannot be played this way\n", index + 1))
+                    }
+                }
+            }
+        }
+        text
+    }
+
+    pub fn total_score(&self, lines: &[Line], interpretation: Interpretation) -> Option<u64> {
+        lines
+            .iter()
+            .map(|&line| Some(self.play(line, interpretation)?.score()))
+            .sum()
+    }
+}
+
+impl Played<'_> {
+    pub fn shape_score(&self) -> u64 {
+        self.shape.score
+    }
+
+    pub fn outcome_score(&self) -> u64 {
+        self.points
+    }
+
+    pub fn score(&self) -> u64 {
+        self.shape_score() + self.outcome_score()
+    }
+}
+
+/// One directive per line, `#` starting a comment; see [`RPS`] and [`RPSLS`].
+impl FromStr for Rules {
+    type Err = Error;
+
+    fn from_str(config: &str) -> Result<Self> {
+        let mut shapes: Vec<ShapeRule> = Vec::new();
+        let mut points = None;
+        let mut outcomes = None;
+        // winner and loser, looked up once every shape is known
+        let mut beats = Vec::new();
+
+        for line in config.lines() {
+            let line = line.split_once('#').map_or(line, |(before, _)| before);
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let Some((&keyword, args)) = words.split_first() else {
+                continue;
+            };
+            match keyword {
+                "shape" => {
+                    let [name, score, opponent, response] = args[..] else {
+                        return Err(Error::at(
+                            config,
+                            line,
+                            "expected `shape <name> <score> <opponent letter> <response letter>`",
+                        ));
+                    };
+                    if shapes.iter().any(|shape| shape.name == name) {
+                        return Err(Error::at(
+                            config,
+                            name,
+                            format!("{} is already a shape", name),
+                        ));
+                    }
+                    let score = score
+                        .parse()
+                        .map_err(|_| Error::at(config, score, "a score is a number"))?;
+                    for (letter, taken) in [
+                        (opponent, shapes.iter().find(|s| s.opponent == opponent)),
+                        (response, shapes.iter().find(|s| s.response == response)),
+                    ] {
+                        if let Some(shape) = taken {
+                            return Err(Error::at(
+                                config,
+                                letter,
+                                format!("{} already stands for {}", letter, shape.name),
+                            ));
+                        }
+                    }
+                    shapes.push(ShapeRule {
+                        name: name.to_string(),
+                        score,
+                        opponent: opponent.to_string(),
+                        response: response.to_string(),
+                    });
+                }
+                "points" => {
+                    let [loss, draw, win] = args[..] else {
+                        return Err(Error::at(
+                            config,
+                            line,
+                            "expected `points <loss> <draw> <win>`",
+                        ));
+                    };
+                    if points.is_some() {
+                        return Err(Error::at(config, keyword, "points are given twice"));
+                    }
+                    let number = |points: &str| {
+                        points
+                            .parse()
+                            .map_err(|_| Error::at(config, points, "points are a number"))
+                    };
+                    points = Some(Points {
+                        loss: number(loss)?,
+                        draw: number(draw)?,
+                        win: number(win)?,
+                    });
+                }
+                "outcomes" => {
+                    let [lose, draw, win] = args[..] else {
+                        return Err(Error::at(
+                            config,
+                            line,
+                            "expected `outcomes <lose letter> <draw letter> <win letter>`",
+                        ));
+                    };
+                    if outcomes.is_some() {
+                        return Err(Error::at(config, keyword, "outcomes are given twice"));
+                    }
+                    if lose == draw || draw == win || lose == win {
+                        return Err(Error::at(
+                            config,
+                            line,
+                            "each outcome needs a letter of its own",
+                        ));
+                    }
+                    outcomes = Some([lose, draw, win].map(String::from));
+                }
+                "beats" => {
+                    let Some((&winner, losers)) = args.split_first().filter(|(_, l)| !l.is_empty())
+                    else {
+                        return Err(Error::at(
+                            config,
+                            line,
+                            "expected `beats <shape> <beaten shape>..`",
+                        ));
+                    };
+                    beats.extend(losers.iter().map(|&loser| (winner, loser)));
+                }
+                _ => {
+                    return Err(Error::at(
+                        config,
+                        keyword,
+                        "expected `shape`, `points`, `outcomes` or `beats`",
+                    ))
+                }
+            }
+        }
+
+        let count = shapes.len();
+        if count == 0 {
+            return Err(Error::InvalidInput("the rules have no shapes".to_string()));
+        }
+        let mut matrix = vec![vec![false; count]; count];
+        if beats.is_empty() {
+            // a cyclic tournament, where each shape beats the half of the
+            // others listed just before it
+            if count.is_multiple_of(2) {
+                return Err(Error::InvalidInput(format!(
+                    "{} shapes cannot all beat as many as they lose to; \
+                     list who beats whom with `beats`",
+                    count
+                )));
+            }
+            for (shape, row) in matrix.iter_mut().enumerate() {
+                for back in 1..=count / 2 {
+                    row[(shape + count - back) % count] = true;
+                }
+            }
+        }
+        let find = |name: &str| {
+            shapes
+                .iter()
+                .position(|shape| shape.name == name)
+                .ok_or_else(|| Error::at(config, name, format!("no shape is called {}", name)))
+        };
+        for (winner, loser) in beats {
+            let (shape, other) = (find(winner)?, find(loser)?);
+            if shape == other {
+                return Err(Error::at(
+                    config,
+                    loser,
+                    format!("{} cannot beat itself", winner),
+                ));
+            }
+            if matrix[other][shape] {
+                return Err(Error::at(
+                    config,
+                    loser,
+                    format!("{} already beats {}", loser, winner),
+                ));
+            }
+            matrix[shape][other] = true;
+        }
+
+        Ok(Rules {
+            shapes,
+            beats: matrix,
+            points: points.unwrap_or_default(),
+            outcomes: outcomes.unwrap_or_else(|| ["X", "Y", "Z"].map(String::from)),
+        })
+    }
+}
+
+/// The round's breakdown, e.g. `paper vs rock: win, 2 + 6 = 8`.
+impl fmt::Display for Played<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} vs {}: {}, {} + {} = {}",
+            self.shape.name,
+            self.opponent.name,
+            self.outcome,
+            self.shape_score(),
+            self.outcome_score(),
+            self.score()
+        )
+    }
+}
+
+// e.g. "expected A, B or C"
+fn expected<'a>(letters: impl IntoIterator<Item = &'a str>) -> String {
+    let letters: Vec<&str> = letters.into_iter().collect();
+    match letters.split_last() {
+        Some((last, [])) => format!("expected {}", last),
+        Some((last, rest)) => format!("expected {} or {}", rest.join(", "), last),
+        None => "expected nothing".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard() {
+        let rules = Rules::standard();
+        let shapes: Vec<(&str, u64)> = rules
+            .shapes()
+            .iter()
+            .map(|s| (s.name.as_str(), s.score))
+            .collect();
+        assert_eq!(shapes, [("rock", 1), ("paper", 2), ("scissors", 3)]);
+        // rock beats scissors, paper rock and scissors paper
+        let beaten: Vec<Vec<usize>> = (0..3)
+            .map(|shape| (0..3).filter(|&other| rules.beats(shape, other)).collect())
+            .collect();
+        assert_eq!(beaten, [[2], [0], [1]]);
+        assert_eq!(rules.outcome(1, 0), Outcome::Win);
+        assert_eq!(rules.outcome(1, 1), Outcome::Draw);
+        assert_eq!(rules.outcome(1, 2), Outcome::Lose);
+        assert_eq!(rules.response(Outcome::Win, 2), Some(0));
+        assert_eq!(rules.response(Outcome::Lose, 2), Some(1));
+        assert_eq!(
+            rules.points(),
+            Points {
+                loss: 0,
+                draw: 3,
+                win: 6
+            }
+        );
+        assert_eq!(&Rules::default(), rules);
+    }
+
+    #[test]
+    fn test_rpsls() {
+        let rules = Rules::rpsls();
+        assert_eq!(rules.shapes().len(), 5);
+        // every shape beats two and loses to two
+        for shape in 0..5 {
+            assert_eq!((0..5).filter(|&other| rules.beats(shape, other)).count(), 2);
+            assert_eq!((0..5).filter(|&other| rules.beats(other, shape)).count(), 2);
+        }
+        // the same tournament as listing the shapes in cyclic order
+        let cyclic: Rules = "shape rock 1 A V\nshape spock 5 E Z\nshape paper 2 B W\n\
+                             shape lizard 4 D Y\nshape scissors 3 C X"
+            .parse()
+            .unwrap();
+        let index =
+            |rules: &Rules, name: &str| rules.shapes().iter().position(|s| s.name == name).unwrap();
+        for winner in rules.shapes() {
+            for loser in rules.shapes() {
+                assert_eq!(
+                    rules.beats(index(&rules, &winner.name), index(&rules, &loser.name)),
+                    cyclic.beats(index(&cyclic, &winner.name), index(&cyclic, &loser.name)),
+                    "{} against {}",
+                    winner.name,
+                    loser.name
+                );
+            }
+        }
+
+        // lizard vs rock, scissors vs paper, spock vs scissors, scissors vs spock
+        let guide = rules.parse_guide("A Y\nB X\nC Z\nE X").unwrap();
+        let breakdown: Vec<String> = rules
+            .breakdown(&guide, Interpretation::Shape)
+            .unwrap()
+            .iter()
+            .map(Played::to_string)
+            .collect();
+        assert_eq!(
+            breakdown,
+            [
+                "lizard vs rock: lose, 4 + 0 = 4",
+                "scissors vs paper: win, 3 + 6 = 9",
+                "spock vs scissors: win, 5 + 6 = 11",
+                "scissors vs spock: lose, 3 + 0 = 3",
+            ]
+        );
+        assert_eq!(rules.total_score(&guide, Interpretation::Shape), Some(27));
+        // of the two shapes that end a round as asked, the one scoring more
+        let breakdown: Vec<String> = rules
+            .breakdown(&guide, Interpretation::Outcome)
+            .unwrap()
+            .iter()
+            .map(Played::to_string)
+            .collect();
+        assert_eq!(
+            breakdown,
+            [
+                "rock vs rock: draw, 1 + 3 = 4",
+                "spock vs paper: lose, 5 + 0 = 5",
+                "spock vs scissors: win, 5 + 6 = 11",
+                "scissors vs spock: lose, 3 + 0 = 3",
+            ]
+        );
+        assert_eq!(rules.total_score(&guide, Interpretation::Outcome), Some(23));
+
+        // lizard and spock are not shapes of the standard rules
+        assert_eq!(
+            Rules::standard().play(guide[0], Interpretation::Shape),
+            None
+        );
+        assert_eq!(
+            Rules::standard().total_score(&guide, Interpretation::Outcome),
+            None
+        );
+        assert!(Rules::standard()
+            .play(guide[1], Interpretation::Outcome)
+            .is_some());
+
+        // a standard round, rock vs scissors, by the same shapes' names
+        let round: Round = "C X".parse().unwrap();
+        let line = rules.line(round).unwrap();
+        assert_eq!(line, Line::from(round));
+        assert_eq!(
+            rules.play(line, Interpretation::Shape).unwrap().to_string(),
+            "rock vs scissors: win, 1 + 6 = 7"
+        );
+        assert_eq!(
+            rules
+                .play(line, Interpretation::Outcome)
+                .unwrap()
+                .to_string(),
+            "lizard vs scissors: lose, 4 + 0 = 4"
+        );
+
+        // V and W only name shapes
+        let guide = rules.parse_guide("A W\nB V").unwrap();
+        assert_eq!(rules.total_score(&guide, Interpretation::Shape), Some(9));
+        assert_eq!(rules.total_score(&guide, Interpretation::Outcome), None);
+    }
+
+    #[test]
+    fn test_custom() {
+        // a relation that is no tournament: nothing beats the anvil, which
+        // beats nothing either, and the feather only loses
+        let rules: Rules = "
+            shape anvil   10 a 1  # heavy
+            shape hammer   5 h 2
+            shape feather  1 f 3
+            points 1 2 100
+            outcomes L D W
+            beats hammer feather
+        "
+        .parse()
+        .unwrap();
+        assert_eq!(rules.points().win, 100);
+        assert_eq!(rules.outcome(0, 1), Outcome::Draw);
+        assert_eq!(rules.outcome(2, 1), Outcome::Lose);
+        assert_eq!(rules.response(Outcome::Draw, 2), Some(0));
+        assert_eq!(rules.response(Outcome::Win, 0), None);
+
+        let guide = rules.parse_guide("f 2\nh W\nf D").unwrap();
+        assert_eq!(rules.total_score(&guide, Interpretation::Shape), None);
+        assert_eq!(
+            rules.total_score(&guide[..1], Interpretation::Shape),
+            Some(105)
+        );
+        assert_eq!(
+            rules.total_score(&guide[1..], Interpretation::Outcome),
+            None
+        );
+        assert_eq!(
+            rules.total_score(&guide[2..], Interpretation::Outcome),
+            Some(12)
+        );
+        // none of the standard shapes is among these
+        assert_eq!(rules.line("A X".parse().unwrap()), None);
+        let error = rules.parse_line("a X").unwrap_err();
+        assert!(error.to_string().contains("expected 1, 2, 3, L, D or W"));
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("rules");
+        assert_eq!(Rules::load(&dir.join("rps.txt")).unwrap(), Rules::default());
+        assert_eq!(Rules::load(&dir.join("rpsls.txt")).unwrap(), Rules::rpsls());
+        assert!(matches!(
+            Rules::load(&dir.join("missing.txt")),
+            Err(Error::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |config: &str| match config.parse::<Rules>().unwrap_err() {
+            Error::Parse(err) => format!("{}:{}: {}", err.line, err.column, err.message),
+            Error::InvalidInput(message) => message,
+            err => panic!("unexpected error: {}", err),
+        };
+        let shapes = "shape rock 1 A X\nshape paper 2 B Y\nshape scissors 3 C Z\n";
+        assert_eq!(
+            error("shape rock 1 A"),
+            "1:1: expected `shape <name> <score> <opponent letter> <response letter>`"
+        );
+        assert_eq!(error("shape rock one A X"), "1:12: a score is a number");
+        assert_eq!(
+            error(&format!("{}shape rock 4 D W", shapes)),
+            "4:7: rock is already a shape"
+        );
+        assert_eq!(
+            error(&format!("{}shape lizard 4 D Z", shapes)),
+            "4:18: Z already stands for scissors"
+        );
+        assert_eq!(
+            error(&format!("{}points 0 3", shapes)),
+            "4:1: expected `points <loss> <draw> <win>`"
+        );
+        assert_eq!(
+            error(&format!("{}points 0 3 6\npoints 0 3 6", shapes)),
+            "5:1: points are given twice"
+        );
+        assert_eq!(
+            error(&format!("{}outcomes X Y X", shapes)),
+            "4:1: each outcome needs a letter of its own"
+        );
+        assert_eq!(
+            error(&format!("{}wins rock scissors", shapes)),
+            "4:1: expected `shape`, `points`, `outcomes` or `beats`"
+        );
+        assert_eq!(
+            error(&format!("{}beats rock", shapes)),
+            "4:1: expected `beats <shape> <beaten shape>..`"
+        );
+        assert_eq!(
+            error(&format!("{}beats rock lizard", shapes)),
+            "4:12: no shape is called lizard"
+        );
+        assert_eq!(
+            error(&format!("{}beats rock rock", shapes)),
+            "4:12: rock cannot beat itself"
+        );
+        assert_eq!(
+            error(&format!("{}beats rock paper\nbeats paper rock", shapes)),
+            "5:13: rock already beats paper"
+        );
+        assert_eq!(error("# nothing"), "the rules have no shapes");
+        assert!(error("shape rock 1 A X\nshape paper 2 B Y").contains("2 shapes"));
+    }
+}
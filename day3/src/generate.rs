@@ -0,0 +1,100 @@
+//! Random rucksacks, with both answers.
+//!
+//! Each group of three gets a badge, and every other item type is kept out
+//! of at least one rucksack of the group, so the badge is the only type all
+//! three carry. Each rucksack likewise puts one shared item in both
+//! compartments and splits its other types between them.
+
+use aoc_common::generate::{Generated, Rng};
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub groups: usize,
+    /// At least two.
+    pub max_compartment: usize,
+}
+
+/// About the size of a real input.
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            groups: 100,
+            max_compartment: 24,
+        }
+    }
+}
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn priority(item: u8) -> u32 {
+    match item {
+        b'a'..=b'z' => u32::from(item - b'a') + 1,
+        _ => u32::from(item - b'A') + 27,
+    }
+}
+
+pub fn generate(seed: u64, params: &Params) -> Generated {
+    assert!(params.max_compartment >= 2, "compartments hold two items");
+    let mut rng = Rng::new(seed);
+    let (mut part_one, mut part_two) = (0, 0);
+    let mut rucksacks = Vec::with_capacity(params.groups * 3);
+    for _ in 0..params.groups {
+        let badge = *rng.choose(ITEMS);
+        part_two += priority(badge);
+        // which elf of the group leaves each item type out
+        let left_out: Vec<usize> = ITEMS.iter().map(|_| rng.below(3)).collect();
+
+        for elf in 0..3 {
+            let mut allowed: Vec<u8> = ITEMS
+                .iter()
+                .zip(&left_out)
+                .filter(|&(&item, &out)| item != badge && out != elf)
+                .map(|(&item, _)| item)
+                .collect();
+            rng.shuffle(&mut allowed);
+            // the shared item is the badge or one of the allowed types
+            let shared = if rng.chance(0.1) {
+                badge
+            } else {
+                *rng.choose(&allowed)
+            };
+            allowed.retain(|&item| item != shared);
+            part_one += priority(shared);
+
+            let (left_types, right_types) = allowed.split_at(allowed.len() / 2);
+            let size = rng.between(2..=params.max_compartment as i64) as usize;
+            let mut left = vec![shared, badge];
+            let mut right = vec![shared];
+            left.extend((2..size).map(|_| *rng.choose(left_types)));
+            right.extend((1..size).map(|_| *rng.choose(right_types)));
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            left.extend(right);
+            rucksacks.push(String::from_utf8(left).unwrap());
+        }
+    }
+
+    Generated::new(rucksacks.join("\n")).with_answers(part_one, part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day3;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            generate(1, &Params::default()),
+            generate(1, &Params::default())
+        );
+        for seed in 0..20 {
+            generate(seed, &Params::default()).assert_solved_by::<Day3>();
+        }
+        let tiny = Params {
+            groups: 50,
+            max_compartment: 2,
+        };
+        generate(0, &tiny).assert_solved_by::<Day3>();
+    }
+}
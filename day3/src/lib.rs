@@ -0,0 +1,175 @@
+pub mod generate;
+
+use std::{char, collections::HashMap};
+
+use aoc_common::{parallel, Answer, Error, Result, Solution};
+use aoc_macros::aoc;
+
+pub struct Day3;
+
+#[aoc(day = 3)]
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut rucksacks = Vec::new();
+        for line in input.lines() {
+            // items are letters, split evenly between the two compartments
+            if let Some((index, item)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic())
+            {
+                let span = &line[index..index + item.len_utf8()];
+                return Err(Error::at(input, span, format!("{:?} is not an item", item)));
+            }
+            if line.len() % 2 != 0 {
+                return Err(Error::at(
+                    input,
+                    line,
+                    "compartments must hold as many items",
+                ));
+            }
+            rucksacks.push(line.to_string());
+        }
+        if rucksacks.len() % 3 != 0 {
+            return Err(Error::InvalidInput(format!(
+                "{} rucksacks do not make groups of three elves",
+                rucksacks.len()
+            )));
+        }
+        Ok(rucksacks)
+    }
+
+    fn part_one(rucksacks: &Self::Input) -> Answer {
+        // every rucksack is scored on its own
+        let priorities = parallel::map(rucksacks, |rucksack| {
+            let (first_compartment, second_compartment) = split_str_in_half(rucksack);
+            let first_compartment_occurrence_map = compartment_occurrence_map(first_compartment);
+            let second_compartment_occurrence_map = compartment_occurrence_map(second_compartment);
+            compare_occurence_maps(
+                &first_compartment_occurrence_map,
+                &second_compartment_occurrence_map,
+            )
+        });
+        priorities.into_iter().sum::<u32>().into()
+    }
+
+    fn part_two(rucksacks: &Self::Input) -> Answer {
+        // and so is every group of three elves
+        let priorities = parallel::map(rucksacks.chunks(3), |group| {
+            let occurence_maps = group
+                .iter()
+                .map(|rucksack| compartment_occurrence_map(rucksack))
+                .collect::<Vec<HashMap<char, u32>>>();
+            find_common_key_in_maps(&[&occurence_maps[0], &occurence_maps[1], &occurence_maps[2]])
+                .map_or(0, compute_priority)
+        });
+        priorities.into_iter().sum::<u32>().into()
+    }
+}
+
+fn split_str_in_half(str: &str) -> (&str, &str) {
+    let half = str.len() / 2;
+    let (first, second) = str.split_at(half);
+    (first, second)
+}
+
+fn compartment_occurrence_map(rucksack_compartment: &str) -> HashMap<char, u32> {
+    let mut type_occurrence_map: HashMap<char, u32> = HashMap::new();
+    for c in rucksack_compartment.chars() {
+        let count = type_occurrence_map.entry(c).or_insert(0);
+        *count += 1;
+    }
+    type_occurrence_map
+}
+
+fn compare_occurence_maps(
+    first_occurence_map: &HashMap<char, u32>,
+    second_occurence_map: &HashMap<char, u32>,
+) -> u32 {
+    let mut result: u32 = 0;
+    first_occurence_map.clone().into_keys().for_each(|x| {
+        if check_occurence_map(second_occurence_map, x) {
+            result += compute_priority(x);
+        }
+    });
+    result
+}
+
+fn find_common_key_in_maps(occurence_map_slice: &[&HashMap<char, u32>; 3]) -> Option<char> {
+    occurence_map_slice[0].clone().into_keys().find(|&x| {
+        check_occurence_map(occurence_map_slice[1], x)
+            && check_occurence_map(occurence_map_slice[2], x)
+    })
+}
+
+fn check_occurence_map(occurence_map: &HashMap<char, u32>, item: char) -> bool {
+    occurence_map.keys().any(|&x| x == item)
+}
+
+fn compute_priority(item: char) -> u32 {
+    match item {
+        'a' => 1,
+        'b' => 2,
+        'c' => 3,
+        'd' => 4,
+        'e' => 5,
+        'f' => 6,
+        'g' => 7,
+        'h' => 8,
+        'i' => 9,
+        'j' => 10,
+        'k' => 11,
+        'l' => 12,
+        'm' => 13,
+        'n' => 14,
+        'o' => 15,
+        'p' => 16,
+        'q' => 17,
+        'r' => 18,
+        's' => 19,
+        't' => 20,
+        'u' => 21,
+        'v' => 22,
+        'w' => 23,
+        'x' => 24,
+        'y' => 25,
+        'z' => 26,
+        'A' => 27,
+        'B' => 28,
+        'C' => 29,
+        'D' => 30,
+        'E' => 31,
+        'F' => 32,
+        'G' => 33,
+        'H' => 34,
+        'I' => 35,
+        'J' => 36,
+        'K' => 37,
+        'L' => 38,
+        'M' => 39,
+        'N' => 40,
+        'O' => 41,
+        'P' => 42,
+        'Q' => 43,
+        'R' => 44,
+        'S' => 45,
+        'T' => 46,
+        'U' => 47,
+        'V' => 48,
+        'W' => 49,
+        'X' => 50,
+        'Y' => 51,
+        'Z' => 52,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parallel() {
+        let inputs = (0..5).map(|seed| generate::generate(seed, &Default::default()).input);
+        parallel::assert_matches_sequential::<Day3>(inputs);
+    }
+}
@@ -0,0 +1,69 @@
+//! Random section assignment pairs, with both answers.
+
+use aoc_common::generate::{Generated, Rng};
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub pairs: usize,
+    /// Sections are numbered from 1 up to this.
+    pub sections: u32,
+}
+
+/// About the size of a real input.
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            pairs: 1000,
+            sections: 99,
+        }
+    }
+}
+
+pub fn generate(seed: u64, params: &Params) -> Generated {
+    let mut rng = Rng::new(seed);
+    let mut assignment = || {
+        let a = rng.between(1..=i64::from(params.sections));
+        let b = rng.between(1..=i64::from(params.sections));
+        (a.min(b), a.max(b))
+    };
+    let (mut contained, mut overlapping) = (0, 0);
+    let pairs: Vec<String> = (0..params.pairs)
+        .map(|_| {
+            let (left, right) = (assignment(), assignment());
+            if (left.0 <= right.0 && right.1 <= left.1) || (right.0 <= left.0 && left.1 <= right.1)
+            {
+                contained += 1;
+            }
+            if left.0 <= right.1 && right.0 <= left.1 {
+                overlapping += 1;
+            }
+            format!("{}-{},{}-{}", left.0, left.1, right.0, right.1)
+        })
+        .collect();
+
+    Generated::new(pairs.join("\n")).with_answers(contained, overlapping)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day4;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            generate(1, &Params::default()),
+            generate(1, &Params::default())
+        );
+        for seed in 0..20 {
+            generate(seed, &Params::default()).assert_solved_by::<Day4>();
+        }
+        let one_section = Params {
+            pairs: 10,
+            sections: 1,
+        };
+        let generated = generate(0, &one_section);
+        assert_eq!(generated.part_one, Some(10.into()));
+        generated.assert_solved_by::<Day4>();
+    }
+}
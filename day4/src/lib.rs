@@ -0,0 +1,176 @@
+pub mod generate;
+
+use aoc_common::{parse, Answer, Error, Result, Solution};
+use aoc_macros::aoc;
+
+pub struct Day4;
+
+#[aoc(day = 4)]
+impl Solution for Day4 {
+    type Input = Vec<([u32; 2], [u32; 2])>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_pairs(input)
+    }
+
+    fn part_one(pairs: &Self::Input) -> Answer {
+        let mut included_section_assignment_count: u32 = 0;
+
+        // Go through parsed sections and check if they are included in each other
+        for (left_pair, right_pair) in pairs.iter() {
+            if check_inclusion(left_pair, right_pair) || check_inclusion(right_pair, left_pair) {
+                included_section_assignment_count += 1;
+            }
+        }
+
+        included_section_assignment_count.into()
+    }
+
+    // Part two: count the number of overlapping sections
+    fn part_two(pairs: &Self::Input) -> Answer {
+        let mut overlapping_section_assignment_count: u32 = 0;
+        for (left_pair, right_pair) in pairs.iter() {
+            if check_overlap(left_pair, right_pair) {
+                overlapping_section_assignment_count += 1;
+            }
+        }
+
+        overlapping_section_assignment_count.into()
+    }
+}
+
+fn parse_pairs(input: &str) -> Result<Vec<([u32; 2], [u32; 2])>> {
+    // Split input into lines, then into pairs, then parse sections into numbers:
+    // e.g. "3-10" -> [3, 10]
+    input
+        .lines()
+        .map(|line| {
+            let (left_pair, right_pair) = parse::split_once(input, line, ",")?;
+            Ok((
+                parse_section(input, left_pair)?,
+                parse_section(input, right_pair)?,
+            ))
+        })
+        .collect()
+}
+
+fn check_inclusion(included_section: &[u32; 2], parent_section: &[u32; 2]) -> bool {
+    included_section[0] >= parent_section[0] && included_section[1] <= parent_section[1]
+}
+
+fn check_overlap(left_section: &[u32; 2], right_section: &[u32; 2]) -> bool {
+    let left_low_overlap =
+        left_section[0] <= right_section[0] && left_section[1] >= right_section[0];
+    let left_high_overlap =
+        left_section[0] <= right_section[1] && left_section[1] >= right_section[1];
+    let left_included_overlap =
+        left_section[0] >= right_section[0] && left_section[1] <= right_section[1];
+
+    let left_overlap = left_low_overlap || left_high_overlap || left_included_overlap;
+
+    let right_low_overlap =
+        right_section[0] <= left_section[0] && right_section[1] >= left_section[0];
+    let right_high_overlap =
+        right_section[0] <= left_section[1] && right_section[1] >= left_section[1];
+    let right_included_overlap =
+        right_section[0] >= left_section[0] && right_section[1] <= left_section[1];
+
+    let right_overlap = right_low_overlap || right_high_overlap || right_included_overlap;
+
+    left_overlap || right_overlap
+}
+
+fn parse_section(input: &str, section: &str) -> Result<[u32; 2]> {
+    let (first, second) = parse::split_once(input, section, "-")?;
+    let section_bounds = [parse::number(input, first)?, parse::number(input, second)?];
+    if section_bounds[0] > section_bounds[1] {
+        return Err(Error::at(input, section, "section ends before it starts"));
+    }
+    Ok(section_bounds)
+}
+
+// Testing util functions
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    #[test]
+    fn test_check_overlap() {
+        // With overlap on the left
+        assert!(check_overlap(&[1, 5], &[3, 7]));
+
+        // With no overlap
+        assert!(!check_overlap(&[1, 5], &[6, 7]));
+
+        // With overlap on the right
+        assert!(check_overlap(&[3, 7], &[1, 5]));
+
+        // With "inclusive" overlap
+        assert!(check_overlap(&[1, 7], &[3, 5]));
+        assert!(check_overlap(&[3, 5], &[1, 7]));
+
+        // Edge cases
+        assert!(check_overlap(&[1, 5], &[1, 5]));
+        assert!(!check_overlap(&[1, 1], &[2, 2]));
+        assert!(check_overlap(&[1, 2], &[2, 2]));
+    }
+
+    #[test]
+    fn test_parse_section() {
+        let parsed = parse_section("2-5", "2-5").unwrap();
+        assert_eq!(parsed, [2, 5]);
+
+        // Error case
+        let parsed = parse_section("2-5-6", "2-5-6");
+        assert!(parsed.is_err());
+        assert!(parse_section("5-2", "5-2").is_err());
+    }
+
+    fn render_pairs(pairs: &[([u32; 2], [u32; 2])]) -> String {
+        pairs
+            .iter()
+            .map(|(left, right)| format!("{}-{},{}-{}\n", left[0], left[1], right[0], right[1]))
+            .collect()
+    }
+
+    fn section() -> impl Strategy<Value = [u32; 2]> {
+        (any::<u32>(), any::<u32>()).prop_map(|(a, b)| [a.min(b), a.max(b)])
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_round_trip(pairs in prop::collection::vec((section(), section()), 0..20)) {
+            prop_assert_eq!(parse_pairs(&render_pairs(&pairs)).unwrap(), pairs);
+        }
+
+        #[test]
+        fn test_parse_never_panics(input in "[0-9,\\-\n]{0,40}") {
+            let _ = parse_pairs(&input);
+        }
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let input = "2-4,6-8\n2-3,4-x\n";
+        match parse_pairs(input).unwrap_err() {
+            aoc_common::Error::Parse(err) => {
+                assert_eq!((err.line, err.column), (2, 7));
+                assert_eq!(err.span(), "x");
+            }
+            err => panic!("unexpected error: {}", err),
+        }
+    }
+
+    #[test]
+    fn test_check_inclusion() {
+        let included = check_inclusion(&[1, 5], &[1, 10]);
+        assert!(included);
+
+        let right_included = check_inclusion(&[5, 15], &[6, 10]);
+        // This is false as check_inclusion checks if the first section is included in the second
+        assert!(!right_included);
+
+        let non_included = check_inclusion(&[1, 5], &[2, 10]);
+        assert!(!non_included);
+    }
+}
@@ -0,0 +1,133 @@
+//! Random crate stacks and moves, with both answers.
+//!
+//! Moves only take crates a stack has. Both crane models move the same number
+//! of crates each time, so the stack heights, and with them the validity of
+//! every move, agree between the two parts.
+
+use aoc_common::generate::{Generated, Rng};
+
+use crate::Move;
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// At most nine, as the drawing numbers stacks with one digit.
+    pub stacks: usize,
+    pub max_height: usize,
+    pub moves: usize,
+}
+
+/// About the size of a real input.
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            stacks: 9,
+            max_height: 8,
+            moves: 500,
+        }
+    }
+}
+
+pub fn generate(seed: u64, params: &Params) -> Generated {
+    assert!(
+        (1..=9).contains(&params.stacks),
+        "between one and nine stacks"
+    );
+    let mut rng = Rng::new(seed);
+    let stacks: Vec<Vec<char>> = (0..params.stacks)
+        .map(|_| {
+            (0..rng.between(1..=params.max_height as i64))
+                .map(|_| char::from(b'A' + rng.below(26) as u8))
+                .collect()
+        })
+        .collect();
+    let drawing = draw(&stacks);
+
+    // part one moves crates one at a time, part two all at once
+    let (mut one_at_a_time, mut all_at_once) = (stacks.clone(), stacks);
+    let mut moves = Vec::with_capacity(params.moves);
+    for _ in 0..params.moves {
+        let filled: Vec<usize> = (0..params.stacks)
+            .filter(|&stack| !one_at_a_time[stack].is_empty())
+            .collect();
+        if params.stacks < 2 || filled.is_empty() {
+            break;
+        }
+        let from = *rng.choose(&filled);
+        let to = (from + 1 + rng.below(params.stacks - 1)) % params.stacks;
+        let count = rng.between(1..=one_at_a_time[from].len() as i64) as usize;
+
+        let height = one_at_a_time[from].len() - count;
+        let lifted: Vec<char> = one_at_a_time[from].drain(height..).rev().collect();
+        one_at_a_time[to].extend(lifted);
+        let lifted: Vec<char> = all_at_once[from].drain(height..).collect();
+        all_at_once[to].extend(lifted);
+
+        moves.push(Move { count, from, to }.to_string());
+    }
+
+    let tops = |stacks: &[Vec<char>]| -> String {
+        stacks.iter().filter_map(|stack| stack.last()).collect()
+    };
+    Generated::new(format!("{}\n\n{}", drawing, moves.join("\n")))
+        .with_answers(tops(&one_at_a_time), tops(&all_at_once))
+}
+
+// The drawing of `stacks`, listed bottom to top, the way the puzzle draws it
+fn draw(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut rows: Vec<String> = (0..height)
+        .rev()
+        .map(|level| {
+            stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(label) => format!("[{}]", label),
+                    None => "   ".to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect();
+    rows.push(
+        (1..=stacks.len())
+            .map(|number| format!(" {} ", number))
+            .collect::<Vec<_>>()
+            .join(" "),
+    );
+    rows.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day5;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            generate(1, &Params::default()),
+            generate(1, &Params::default())
+        );
+        for seed in 0..20 {
+            generate(seed, &Params::default()).assert_solved_by::<Day5>();
+        }
+        let single = Params {
+            stacks: 1,
+            max_height: 3,
+            moves: 10,
+        };
+        generate(0, &single).assert_solved_by::<Day5>();
+    }
+
+    #[test]
+    fn test_draw() {
+        let stacks = [vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+        assert_eq!(
+            draw(&stacks),
+            include_str!("../../inputs/examples/day05.txt")
+                .split_once("\n\n")
+                .unwrap()
+                .0
+        );
+    }
+}
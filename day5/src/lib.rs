@@ -0,0 +1,269 @@
+pub mod generate;
+
+use aoc_common::{parse, Answer, Error, Result, Solution};
+use aoc_macros::aoc;
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+impl FromStr for Move {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        // split the string on the spaces
+        let parts: Vec<&str> = s.split(' ').collect();
+
+        // check that the string has the correct number of parts
+        let [verb, count, from_keyword, from, to_keyword, to] = parts[..] else {
+            return Err(Error::at(
+                s,
+                s,
+                "expected \"move <count> from <stack> to <stack>\"",
+            ));
+        };
+        for (word, expected) in [(verb, "move"), (from_keyword, "from"), (to_keyword, "to")] {
+            if word != expected {
+                return Err(Error::at(s, word, format!("expected {:?}", expected)));
+            }
+        }
+
+        // parse the three parts that contain numbers, stacks being numbered from 1
+        let stack = |number: &str| match parse::number::<usize>(s, number)? {
+            0 => Err(Error::at(s, number, "stacks are numbered from 1")),
+            number => Ok(number - 1),
+        };
+        Ok(Move {
+            count: parse::number(s, count)?,
+            from: stack(from)?,
+            to: stack(to)?,
+        })
+    }
+}
+
+/// Writes the instruction back out, stacks numbered from 1 again.
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.count,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+impl Move {
+    // Part one
+    fn move_stacks<T>(stacks: &mut [Vec<T>], stack_move: Move) {
+        (0..stack_move.count).for_each(|_| {
+            if let Some(popped) = stacks[stack_move.from].pop() {
+                stacks[stack_move.to].push(popped);
+            }
+        });
+    }
+
+    // Part two
+    fn move_stacks_keep_order<T>(stacks: &mut [Vec<T>], stack_move: Move) {
+        let mut temp_stack = Vec::new();
+        (0..stack_move.count).for_each(|_| {
+            if let Some(popped) = stacks[stack_move.from].pop() {
+                temp_stack.push(popped);
+            }
+        });
+        temp_stack.reverse();
+        stacks[stack_move.to].append(&mut temp_stack);
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Cargo {
+    // each stack is listed bottom to top
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+pub struct Day5;
+
+#[aoc(day = 5)]
+impl Solution for Day5 {
+    type Input = Cargo;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let Some((drawing, instructions)) = input.split_once("\n\n") else {
+            return Err(Error::InvalidInput(
+                "expected a blank line between the stacks and the moves".to_string(),
+            ));
+        };
+        let stacks = parse_input_stacks(drawing);
+        let moves = instructions
+            .lines()
+            .map(|line| {
+                let stack_move = line
+                    .parse::<Move>()
+                    .map_err(|err| err.rebase(input, line))?;
+                if stack_move.from.max(stack_move.to) >= stacks.len() {
+                    return Err(Error::at(
+                        input,
+                        line,
+                        format!("there are only {} stacks", stacks.len()),
+                    ));
+                }
+                Ok(stack_move)
+            })
+            .collect::<Result<Vec<Move>>>()?;
+        Ok(Cargo { stacks, moves })
+    }
+
+    fn part_one(cargo: &Self::Input) -> Answer {
+        let mut stacks = cargo.stacks.clone();
+
+        // move stacks
+        cargo
+            .moves
+            .iter()
+            .for_each(|instruction| Move::move_stacks(&mut stacks, instruction.clone()));
+
+        top_of_each_stack(&stacks).into()
+    }
+
+    fn part_two(cargo: &Self::Input) -> Answer {
+        let mut stacks = cargo.stacks.clone();
+
+        cargo
+            .moves
+            .iter()
+            .for_each(|instruction| Move::move_stacks_keep_order(&mut stacks, instruction.clone()));
+
+        top_of_each_stack(&stacks).into()
+    }
+}
+
+fn top_of_each_stack(stacks: &[Vec<char>]) -> String {
+    stacks.iter().filter_map(|stack| stack.last()).collect()
+}
+
+// Parses the crate drawing, e.g.
+//     [D]
+// [N] [C]
+// [Z] [M] [P]
+//  1   2   3
+// into stacks listed bottom to top: [[Z, N], [M, C, D], [P]]
+fn parse_input_stacks(input: &str) -> Vec<Vec<char>> {
+    let mut rows: Vec<&str> = input.lines().collect();
+    // the last line only numbers the stacks
+    let stack_count = rows
+        .pop()
+        .map_or(0, |numbers| numbers.split_whitespace().count());
+    let mut stacks = vec![Vec::new(); stack_count];
+    for row in rows.into_iter().rev() {
+        // crate labels sit at columns 1, 5, 9, ...
+        row.chars()
+            .skip(1)
+            .step_by(4)
+            .enumerate()
+            .filter(|(_, label)| label.is_ascii_alphabetic())
+            .for_each(|(index, label)| {
+                if let Some(stack) = stacks.get_mut(index) {
+                    stack.push(label);
+                }
+            });
+    }
+    stacks
+}
+
+// Write unit tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_move() {
+        let input = "move 13 from 3 to 9";
+        let expected = Move {
+            count: 13,
+            from: 2,
+            to: 8,
+        };
+        let actual = input.parse::<Move>().unwrap();
+        assert_eq!(expected, actual);
+
+        match "move 1 from 0 to 2".parse::<Move>().unwrap_err() {
+            Error::Parse(err) => assert_eq!((err.column, err.span()), (13, "0")),
+            err => panic!("unexpected error: {}", err),
+        }
+        assert!("move 1 to 2".parse::<Move>().is_err());
+        assert!("move 1 onto 2 to 3".parse::<Move>().is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_move_round_trip(count: usize, from in 0..usize::MAX, to in 0..usize::MAX) {
+            let stack_move = Move { count, from, to };
+            prop_assert_eq!(stack_move.to_string().parse::<Move>().unwrap(), stack_move);
+        }
+
+        #[test]
+        fn test_parse_move_never_panics(input in "(move|from|to| |[0-9]{1,3}|-){0,10}") {
+            let _ = input.parse::<Move>();
+        }
+    }
+
+    #[test]
+    fn test_move_stacks() {
+        let mut stacks = vec![vec!["a", "b", "c"], vec!["d", "e", "f"]];
+        let move_stacks = Move {
+            count: 1,
+            from: 0,
+            to: 1,
+        };
+        Move::move_stacks(&mut stacks, move_stacks);
+        let expected = vec![vec!["a", "b"], vec!["d", "e", "f", "c"]];
+        assert_eq!(expected, stacks);
+    }
+
+    #[test]
+    fn test_move_stacks_with_multiple_instructions() {
+        let mut stacks = vec![vec!["a", "b", "c"], vec!["d", "e", "f"]];
+        let move_stacks = vec![
+            Move {
+                count: 1,
+                from: 0,
+                to: 1,
+            },
+            Move {
+                count: 2,
+                from: 1,
+                to: 0,
+            },
+        ];
+        move_stacks
+            .into_iter()
+            .for_each(|instruction| Move::move_stacks(&mut stacks, instruction));
+        let expected = vec![vec!["a", "b", "c", "f"], vec!["d", "e"]];
+        assert_eq!(expected, stacks);
+    }
+
+    #[test]
+    fn test_parse_input_stacks() {
+        let input = "    [D]    \n\
+                     [N] [C]    \n\
+                     [Z] [M] [P]\n \
+                      1   2   3 ";
+        let expected = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+        assert_eq!(parse_input_stacks(input), expected);
+    }
+
+    #[test]
+    fn integration_test() {
+        let input = include_str!("../../inputs/examples/day05.txt");
+        let cargo = Day5::parse(input).unwrap();
+        assert_eq!(Day5::part_one(&cargo), Answer::from("CMZ"));
+        assert_eq!(Day5::part_two(&cargo), Answer::from("MCD"));
+    }
+}
@@ -0,0 +1,68 @@
+//! Random datastreams, with both answers.
+//!
+//! The stream starts with letters from a set of three, so no four in a row
+//! differ, and ends its run with those three and a fourth letter: the first
+//! start-of-packet marker. It goes on with letters from a set of thirteen
+//! holding those four, ends that run with all thirteen and a fourteenth,
+//! the first start-of-message marker, and then anything goes.
+
+use aoc_common::generate::{Generated, Rng};
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// At least 40.
+    pub length: usize,
+}
+
+/// About the size of a real input.
+impl Default for Params {
+    fn default() -> Self {
+        Params { length: 4096 }
+    }
+}
+
+pub fn generate(seed: u64, params: &Params) -> Generated {
+    assert!(params.length >= 40, "too short for both markers");
+    let mut rng = Rng::new(seed);
+    let mut letters: Vec<u8> = (b'a'..=b'z').collect();
+    rng.shuffle(&mut letters);
+    let pick = |rng: &mut Rng, set: &[u8], count: usize| -> Vec<u8> {
+        (0..count).map(|_| *rng.choose(set)).collect()
+    };
+    let quarter = params.length as i64 / 4;
+
+    let run = rng.between(0..=quarter) as usize;
+    let mut stream = pick(&mut rng, &letters[..3], run);
+    stream.extend(&letters[..4]);
+    let packet = stream.len();
+
+    let run = rng.between(0..=quarter) as usize;
+    stream.extend(pick(&mut rng, &letters[..13], run));
+    let mut thirteen = letters[..13].to_vec();
+    rng.shuffle(&mut thirteen);
+    stream.extend(thirteen);
+    stream.push(letters[13]);
+    let message = stream.len();
+
+    let rest = params.length.saturating_sub(stream.len());
+    stream.extend(pick(&mut rng, &letters, rest));
+    Generated::new(String::from_utf8(stream).unwrap()).with_answers(packet, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day6;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            generate(1, &Params::default()),
+            generate(1, &Params::default())
+        );
+        for seed in 0..20 {
+            generate(seed, &Params::default()).assert_solved_by::<Day6>();
+            generate(seed, &Params { length: 40 }).assert_solved_by::<Day6>();
+        }
+    }
+}
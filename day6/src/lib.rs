@@ -1,15 +1,30 @@
-use color_eyre::eyre::Result;
-use std::{collections::HashMap, fs};
-
-fn main() -> Result<()> {
-    // read input.txt
-    let input = fs::read_to_string("src/input.txt")?;
-    part_one(&input);
-    part_two(&input);
-    Ok(())
+pub mod generate;
+
+use aoc_common::{Answer, Result, Solution};
+use aoc_macros::aoc;
+use std::collections::HashMap;
+
+pub struct Day6;
+
+#[aoc(day = 6)]
+impl Solution for Day6 {
+    // the datastream buffer
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim_end().to_string())
+    }
+
+    fn part_one(datastream: &Self::Input) -> Answer {
+        part_one(datastream).into()
+    }
+
+    fn part_two(datastream: &Self::Input) -> Answer {
+        part_two(datastream).into()
+    }
 }
 
-fn part_one(input: &str) {
+fn part_one(input: &str) -> Option<usize> {
     let res: Vec<bool> = input
         .char_indices()
         .map(|(index, _)| input.get(index..index + 4).unwrap_or("aaaa"))
@@ -24,12 +39,10 @@ fn part_one(input: &str) {
         })
         .collect();
     // get the first true in res
-    if let Some(first_true) = res.iter().position(|&x| x) {
-        println!("{:?}", first_true + 4);
-    };
+    res.iter().position(|&x| x).map(|first_true| first_true + 4)
 }
 
-fn part_two(input: &str) {
+fn part_two(input: &str) -> Option<usize> {
     let res: Vec<bool> = input
         .char_indices()
         .map(|(index, _)| input.get(index..index + 14).unwrap_or("aaaaaaaaaaaaaa"))
@@ -43,7 +56,7 @@ fn part_two(input: &str) {
         })
         .collect();
     // get the first true in res
-    if let Some(first_true) = res.iter().position(|&x| x) {
-        println!("{:?}", first_true + 14);
-    };
+    res.iter()
+        .position(|&x| x)
+        .map(|first_true| first_true + 14)
 }
@@ -0,0 +1,158 @@
+//! Random terminal transcripts, with the answer to part one.
+//!
+//! Directory sizes are tallied by name, so every directory gets a name of
+//! its own, and files a name unique within their directory.
+
+use std::collections::HashSet;
+
+use aoc_common::generate::{Generated, Rng};
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// Directories besides `/`.
+    pub dirs: usize,
+    pub max_depth: usize,
+    pub max_files: usize,
+    pub max_size: u32,
+}
+
+/// About the size of a real input.
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            dirs: 180,
+            max_depth: 10,
+            max_files: 5,
+            max_size: 300_000,
+        }
+    }
+}
+
+struct Dir {
+    name: String,
+    parent: usize,
+    depth: usize,
+    files: Vec<(String, u32)>,
+    children: Vec<usize>,
+}
+
+pub fn generate(seed: u64, params: &Params) -> Generated {
+    let mut rng = Rng::new(seed);
+    let mut taken = HashSet::new();
+    let mut dirs = vec![Dir {
+        name: "/".to_string(),
+        parent: 0,
+        depth: 0,
+        files: Vec::new(),
+        children: Vec::new(),
+    }];
+    while dirs.len() <= params.dirs {
+        let name = name(&mut rng);
+        if !taken.insert(name.clone()) {
+            continue;
+        }
+        let parent = loop {
+            let parent = rng.below(dirs.len());
+            if dirs[parent].depth < params.max_depth {
+                break parent;
+            }
+        };
+        let depth = dirs[parent].depth + 1;
+        let index = dirs.len();
+        dirs[parent].children.push(index);
+        dirs.push(Dir {
+            name,
+            parent,
+            depth,
+            files: Vec::new(),
+            children: Vec::new(),
+        });
+    }
+    for dir in &mut dirs {
+        let mut names = HashSet::new();
+        for _ in 0..rng.between(0..=params.max_files as i64) {
+            let mut file = name(&mut rng);
+            if rng.chance(0.7) {
+                file = format!("{}.{}", file, &name(&mut rng)[..3]);
+            }
+            if names.insert(file.clone()) {
+                dir.files
+                    .push((file, rng.between(1..=i64::from(params.max_size)) as u32));
+            }
+        }
+    }
+
+    // children come after their parent, so totals can be summed up backwards
+    let mut sizes: Vec<u32> = dirs
+        .iter()
+        .map(|dir| dir.files.iter().map(|(_, size)| size).sum())
+        .collect();
+    for index in (1..dirs.len()).rev() {
+        sizes[dirs[index].parent] += sizes[index];
+    }
+    let part_one: u32 = sizes.iter().filter(|&&size| size <= 100_000).sum();
+
+    let mut transcript = vec!["$ cd /".to_string()];
+    list(&dirs, 0, &mut transcript);
+    Generated {
+        part_one: Some(part_one.into()),
+        ..Generated::new(transcript.join("\n"))
+    }
+}
+
+// A name of three to eight letters, long enough to rarely repeat
+fn name(rng: &mut Rng) -> String {
+    (0..rng.between(3..=8))
+        .map(|_| char::from(b'a' + rng.below(26) as u8))
+        .collect()
+}
+
+// The commands that list directory `index` and everything below it
+fn list(dirs: &[Dir], index: usize, transcript: &mut Vec<String>) {
+    let dir = &dirs[index];
+    transcript.push("$ ls".to_string());
+    let mut entries: Vec<(&str, String)> = dir
+        .children
+        .iter()
+        .map(|&child| {
+            (
+                dirs[child].name.as_str(),
+                format!("dir {}", dirs[child].name),
+            )
+        })
+        .chain(
+            dir.files
+                .iter()
+                .map(|(name, size)| (name.as_str(), format!("{} {}", size, name))),
+        )
+        .collect();
+    entries.sort();
+    transcript.extend(entries.into_iter().map(|(_, entry)| entry));
+    for &child in &dir.children {
+        transcript.push(format!("$ cd {}", dirs[child].name));
+        list(dirs, child, transcript);
+        transcript.push("$ cd ..".to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day7;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            generate(1, &Params::default()),
+            generate(1, &Params::default())
+        );
+        for seed in 0..20 {
+            generate(seed, &Params::default()).assert_solved_by::<Day7>();
+        }
+        let flat = Params {
+            max_depth: 1,
+            ..Params::default()
+        };
+        generate(0, &flat).assert_solved_by::<Day7>();
+    }
+}
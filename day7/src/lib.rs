@@ -1,3 +1,7 @@
+pub mod generate;
+
+use aoc_common::{parse, Part, Result, Solution};
+use aoc_macros::{aoc, aoc_example};
 use std::collections::{HashMap, HashSet};
 
 #[derive(Default, Debug, PartialEq, Clone)]
@@ -18,12 +22,6 @@ struct FileTreeNodeChild<'a> {
 }
 
 impl<'a> FileTreeNode<'a> {
-    fn new() -> Self {
-        FileTreeNode {
-            children: HashMap::new(),
-        }
-    }
-
     fn insert_file(node: &mut Self, file: FileTreeNodeChild<'a>) {
         if let Some(parent_dir) = file.parent_dirs.last() {
             if let Some(files) = node.children.get_mut(parent_dir) {
@@ -45,28 +43,35 @@ impl FileTree<'_> {
     }
 
     fn insert_depth(tree: &mut Self, depth: u32) {
-        if tree.nodes.get(&depth).is_none() {
-            tree.nodes.insert(depth, FileTreeNode::new());
-        }
+        tree.nodes.entry(depth).or_default();
     }
 }
 
-fn main() {
-    let input = include_str!("input.txt");
-    let lines = input.lines();
-    let tree = construct_tree(lines.collect());
-    let count: u32 = part_one(tree);
+pub struct Day7;
 
-    println!("{:#?}", count);
+#[aoc(day = 7)]
+impl Solution for Day7 {
+    const PARTS: &'static [Part] = &[Part::One];
+
+    // total size of every directory, keyed by directory name
+    type Input = HashMap<String, u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let tree = construct_tree(input)?;
+        Ok(compute_dir_sizes(tree)
+            .into_iter()
+            .map(|(dir, size)| (dir.to_string(), size))
+            .collect())
+    }
 }
 
-fn construct_tree(lines: Vec<&str>) -> FileTree {
+fn construct_tree(input: &str) -> Result<FileTree<'_>> {
     let mut tree = FileTree::new();
     let mut current_depth = 0;
     let mut current_dirs: Vec<&str> = vec!["/"];
 
-    lines.into_iter().for_each(|line| {
-        let parts: Vec<&str> = line.split_whitespace().into_iter().collect();
+    for line in input.lines() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
         if let Some(command) = parts.first() {
             if *command == "$" {
                 if let Some(&"cd") = parts.get(1) {
@@ -76,15 +81,14 @@ fn construct_tree(lines: Vec<&str>) -> FileTree {
                             current_dirs.clear();
                             current_dirs.push("/");
                         }
-                        Some(&"..") => {
-                            if current_depth > 0 {
-                                current_depth -= 1;
-                                current_dirs.pop();
-                                if current_dirs.is_empty() {
-                                    current_dirs.push("/");
-                                }
+                        Some(&"..") if current_depth > 0 => {
+                            current_depth -= 1;
+                            current_dirs.pop();
+                            if current_dirs.is_empty() {
+                                current_dirs.push("/");
                             }
                         }
+                        Some(&"..") => {}
                         Some(dirname) => {
                             current_depth += 1;
                             current_dirs.push(dirname);
@@ -101,7 +105,7 @@ fn construct_tree(lines: Vec<&str>) -> FileTree {
                             let file = FileTreeNodeChild {
                                 parent_dirs: current_dirs.clone(),
                                 _name: filename,
-                                size: size.parse().unwrap(),
+                                size: parse::number(input, size)?,
                             };
                             if let Some(node) = tree.nodes.get_mut(&current_depth) {
                                 FileTreeNode::insert_file(node, file);
@@ -112,12 +116,12 @@ fn construct_tree(lines: Vec<&str>) -> FileTree {
                 }
             }
         }
-    });
+    }
 
-    tree
+    Ok(tree)
 }
 
-fn compute_dir_sizes(tree: FileTree) -> HashMap<&str, u32> {
+fn compute_dir_sizes(tree: FileTree<'_>) -> HashMap<&str, u32> {
     let mut hash_tree_count: HashMap<&str, u32> = HashMap::new();
 
     tree.nodes.into_values().for_each(|node| {
@@ -137,17 +141,11 @@ fn compute_dir_sizes(tree: FileTree) -> HashMap<&str, u32> {
     hash_tree_count
 }
 
-fn part_one(tree: FileTree) -> u32 {
-    let hash_tree_count = compute_dir_sizes(tree);
-
-    println!("{:#?}", hash_tree_count);
-
-    let count: u32 = hash_tree_count
-        .into_values()
-        .filter(|size| *size <= 100_000)
-        .sum();
-
-    count
+#[aoc(day = 7, part = 1)]
+#[aoc_example(expected = 95437)]
+#[aoc_example(file = "../../inputs/day07.txt", expected = 1419174)]
+fn total_size_of_small_dirs(dir_sizes: &HashMap<String, u32>) -> u32 {
+    dir_sizes.values().filter(|size| **size <= 100_000).sum()
 }
 
 #[cfg(test)]
@@ -201,42 +199,4 @@ mod tests {
         let test_file_size: u32 = compute_dir_sizes(tree.clone()).into_values().sum();
         assert_eq!(test_file_size, 800 + (300 + 800) + 300 + 800);
     }
-
-    #[test]
-    fn integration_test() {
-        let input_str = "$ cd /\n\
-                        $ ls\n\
-                        dir a\n\
-                        14848514 b.txt\n\
-                        8504156 c.dat\n\
-                        dir d\n\
-                        $ cd a\n\
-                        $ ls\n\
-                        dir e\n\
-                        29116 f\n\
-                        2557 g\n\
-                        62596 h.lst\n\
-                        $ cd e\n\
-                        $ ls\n\
-                        584 i\n\
-                        $ cd ..\n\
-                        $ cd ..\n\
-                        $ cd d\n\
-                        $ ls\n\
-                        4060174 j\n\
-                        8033020 d.log\n\
-                        5626152 d.ext\n\
-                        7214296 k\n";
-        let lines = input_str.lines();
-        let tree = construct_tree(lines.collect());
-        let count: u32 = part_one(tree);
-        assert_eq!(count, 95437);
-    }
-
-    #[test]
-    fn test_input() {
-        let input_str = include_str!("input.txt");
-        let lines = input_str.lines();
-        assert_eq!(lines.count(), 942);
-    }
 }
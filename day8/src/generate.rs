@@ -0,0 +1,84 @@
+//! Random tree height maps, with the answer to part one.
+
+use aoc_common::generate::{Generated, Rng};
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub width: usize,
+    pub height: usize,
+}
+
+/// About the size of a real input.
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            width: 99,
+            height: 99,
+        }
+    }
+}
+
+pub fn generate(seed: u64, params: &Params) -> Generated {
+    let mut rng = Rng::new(seed);
+    let heights: Vec<Vec<u8>> = (0..params.height)
+        .map(|_| (0..params.width).map(|_| rng.below(10) as u8).collect())
+        .collect();
+    let rows: Vec<String> = heights
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&height| char::from(b'0' + height))
+                .collect()
+        })
+        .collect();
+
+    // walk in from each edge keeping the tallest tree so far, in one pass each
+    let mut visible = vec![vec![false; params.width]; params.height];
+    let mut look = |trees: &mut dyn Iterator<Item = (usize, usize)>| {
+        let mut tallest = None;
+        for (y, x) in trees {
+            if tallest < Some(heights[y][x]) {
+                visible[y][x] = true;
+                tallest = Some(heights[y][x]);
+            }
+        }
+    };
+    for y in 0..params.height {
+        look(&mut (0..params.width).map(|x| (y, x)));
+        look(&mut (0..params.width).rev().map(|x| (y, x)));
+    }
+    for x in 0..params.width {
+        look(&mut (0..params.height).map(|y| (y, x)));
+        look(&mut (0..params.height).rev().map(|y| (y, x)));
+    }
+    let part_one = visible.iter().flatten().filter(|&&seen| seen).count();
+
+    Generated {
+        part_one: Some(part_one.into()),
+        ..Generated::new(rows.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day8;
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            generate(1, &Params::default()),
+            generate(1, &Params::default())
+        );
+        for seed in 0..10 {
+            generate(seed, &Params::default()).assert_solved_by::<Day8>();
+        }
+        let strip = Params {
+            width: 300,
+            height: 2,
+        };
+        let generated = generate(0, &strip);
+        assert_eq!(generated.part_one, Some(600.into()));
+        generated.assert_solved_by::<Day8>();
+    }
+}
@@ -0,0 +1,234 @@
+pub mod generate;
+
+use aoc_common::{geometry::Direction, parallel, Answer, Result, Solution};
+use aoc_grid::{Grid, Pos};
+use aoc_macros::aoc;
+
+pub struct Day8;
+
+#[aoc(day = 8)]
+impl Solution for Day8 {
+    // tree heights
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(grid: &Self::Input) -> Answer {
+        count_visible_cells(grid).into()
+    }
+
+    fn part_two(grid: &Self::Input) -> Answer {
+        compute_max_scenic_score(grid).into()
+    }
+}
+
+fn count_visible_cells(grid: &Grid<u8>) -> usize {
+    parallel::map(grid.positions(), |pos| {
+        is_cell_visible_from_outside_grid(grid, pos)
+    })
+    .into_iter()
+    .filter(|&visible| visible)
+    .count()
+}
+
+fn parse_input(input: &str) -> Result<Grid<u8>> {
+    Grid::parse(input, |c| c.to_digit(10).map(|d| d as u8))
+}
+
+fn is_cell_visible_from_outside_grid(grid: &Grid<u8>, coordinates: Pos) -> bool {
+    let cell = grid[coordinates];
+    // visible when every tree between it and one of the edges is shorter
+    Direction::ALL.into_iter().any(|direction| {
+        grid.ray(coordinates, direction.step())
+            .all(|other| grid[other] < cell)
+    })
+}
+
+fn compute_cell_scenic_score(grid: &Grid<u8>, coordinates: Pos) -> usize {
+    let cell = grid[coordinates];
+    Direction::ALL
+        .into_iter()
+        .map(|direction| {
+            // trees seen in this direction, up to and including the first one
+            // at least as tall
+            let mut seen = 0;
+            for other in grid.ray(coordinates, direction.step()) {
+                seen += 1;
+                if grid[other] >= cell {
+                    break;
+                }
+            }
+            seen
+        })
+        .product()
+}
+
+fn compute_max_scenic_score(grid: &Grid<u8>) -> usize {
+    parallel::map(grid.positions(), |pos| compute_cell_scenic_score(grid, pos))
+        .into_iter()
+        .max()
+        .unwrap_or(0)
+}
+
+// unit tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let test_input = "30373\n\
+                          25512\n\
+                          65332\n\
+                          33549\n\
+                          35390";
+        let test_grid = parse_input(test_input).unwrap();
+        assert_eq!(count_visible_cells(&test_grid), 21);
+    }
+
+    #[test]
+    fn test_is_cell_visible_from_outside_grid() {
+        let test_grid = [vec![3, 9, 1], vec![8, 2, 4], vec![5, 6, 7]];
+        let test_grid = Grid::from_rows(test_grid.to_vec()).unwrap();
+        assert!(!is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(1, 1)
+        ));
+        assert!(is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(2, 1)
+        ));
+        assert!(is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(1, 2)
+        ));
+        assert!(is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(2, 2)
+        ));
+        assert_eq!(count_visible_cells(&test_grid), 8);
+    }
+
+    #[test]
+    fn test_aoc_example() {
+        let test_grid = [
+            vec![3, 0, 3, 7, 3],
+            vec![2, 5, 5, 1, 2],
+            vec![6, 5, 3, 3, 2],
+            vec![3, 3, 5, 4, 9],
+            vec![3, 5, 3, 9, 0],
+        ];
+        let test_grid = Grid::from_rows(test_grid.to_vec()).unwrap();
+        assert_eq!(count_visible_cells(&test_grid), 21);
+    }
+
+    #[test]
+    fn test_is_cell_visible_from_outside_grid_5x5() {
+        let test_grid = [
+            vec![3, 9, 1, 2, 3],
+            vec![8, 2, 4, 5, 6],
+            vec![5, 6, 7, 8, 9],
+            vec![2, 2, 3, 4, 5],
+            vec![2, 2, 3, 4, 5],
+        ];
+        let test_grid = Grid::from_rows(test_grid.to_vec()).unwrap();
+        assert!(!is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(1, 1)
+        ));
+        assert!(is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(2, 1)
+        ));
+        assert!(is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(1, 2)
+        ));
+        assert!(is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(2, 2)
+        ));
+        assert!(is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(3, 2)
+        ));
+        assert!(is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(2, 3)
+        ));
+        assert!(is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(3, 3)
+        ));
+        assert_eq!(count_visible_cells(&test_grid), 23);
+    }
+
+    #[test]
+    fn test_is_cell_visible_from_outside_grid_10x10() {
+        let test_grid = vec![
+            vec![3, 9, 1, 2, 3, 4, 5, 6, 7, 8],
+            vec![8, 2, 4, 5, 6, 7, 8, 9, 1, 2],
+            vec![5, 6, 7, 8, 9, 1, 2, 3, 4, 5],
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 1],
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 1],
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 1],
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 1],
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 1],
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 1],
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 1],
+        ];
+        let test_grid = Grid::from_rows(test_grid.to_vec()).unwrap();
+        assert!(!is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(1, 1)
+        ));
+        assert!(is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(2, 1)
+        ));
+        assert!(is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(1, 2)
+        ));
+        assert!(is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(2, 2)
+        ));
+        assert!(is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(3, 2)
+        ));
+        assert!(is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(2, 3)
+        ));
+        assert!(is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(3, 3)
+        ));
+        assert!(is_cell_visible_from_outside_grid(
+            &test_grid,
+            Pos::new(4, 3)
+        ));
+        assert_eq!(count_visible_cells(&test_grid), 94);
+    }
+
+    #[test]
+    fn test_scenic_score() {
+        let test_input = "30373\n\
+                          25512\n\
+                          65332\n\
+                          33549\n\
+                          35390";
+        let test_grid = parse_input(test_input).unwrap();
+        assert_eq!(compute_max_scenic_score(&test_grid), 8);
+    }
+
+    #[test]
+    fn test_parallel() {
+        let inputs = (0..5).map(|seed| generate::generate(seed, &Default::default()).input);
+        parallel::assert_matches_sequential::<Day8>(inputs);
+    }
+}
@@ -0,0 +1,68 @@
+//! Random rope motions.
+
+use aoc_common::generate::{Generated, Rng};
+
+use crate::MAX_DISTANCE;
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub motions: usize,
+    /// At most [`MAX_DISTANCE`].
+    pub max_distance: usize,
+}
+
+/// About the size of a real input.
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            motions: 2000,
+            max_distance: 20,
+        }
+    }
+}
+
+pub fn generate(seed: u64, params: &Params) -> Generated {
+    assert!(
+        params.max_distance <= MAX_DISTANCE,
+        "motions are at most {} steps",
+        MAX_DISTANCE
+    );
+    let mut rng = Rng::new(seed);
+    let motions: Vec<String> = (0..params.motions)
+        .map(|_| {
+            format!(
+                "{} {}",
+                rng.choose(&['U', 'R', 'D', 'L']),
+                rng.between(1..=params.max_distance as i64)
+            )
+        })
+        .collect();
+    Generated::new(motions.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day9;
+    use aoc_common::{Answer, Part, Solution};
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            generate(1, &Params::default()),
+            generate(1, &Params::default())
+        );
+        for seed in 0..5 {
+            let generated = generate(seed, &Params::default());
+            let steps = Day9::parse(&generated.input).unwrap();
+            for part in Part::ALL {
+                assert!(matches!(Day9::solve(&steps, part), Answer::Number(_)));
+            }
+        }
+        let far = Params {
+            motions: 10,
+            max_distance: MAX_DISTANCE,
+        };
+        Day9::parse(&generate(0, &far).input).unwrap();
+    }
+}
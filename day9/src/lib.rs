@@ -0,0 +1,232 @@
+pub mod generate;
+
+use aoc_common::{
+    geometry::{Direction, Point},
+    parse,
+    simulation::{Simulation, State},
+    Answer, Error, Result, Solution,
+};
+use aoc_macros::aoc;
+use std::collections::HashSet;
+
+type Coordinates = Point<i32>;
+
+pub struct Day9;
+
+#[aoc(day = 9)]
+impl Solution for Day9 {
+    // every motion expanded into single steps
+    type Input = Vec<Direction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut commands = Vec::new();
+        for line in input.lines() {
+            let steps = parse_command(line).map_err(|err| err.rebase(input, line))?;
+            commands.extend(steps);
+        }
+        Ok(commands)
+    }
+
+    fn part_one(commands: &Self::Input) -> Answer {
+        part_one(commands).into()
+    }
+
+    fn part_two(commands: &Self::Input) -> Answer {
+        part_two(commands).into()
+    }
+}
+
+/// The longest motion accepted. Motions are expanded into single steps, so a
+/// longer one would only exhaust memory.
+pub const MAX_DISTANCE: usize = 1 << 16;
+
+/// Parses a motion, e.g. `R 4`, into its single steps.
+pub fn parse_command(command: &str) -> Result<Vec<Direction>> {
+    let (direction, distance_text) = parse::split_once(command, command, " ")?;
+    let distance: usize = parse::number(command, distance_text)?;
+    if distance > MAX_DISTANCE {
+        return Err(Error::at(
+            command,
+            distance_text,
+            format!("motions are at most {} steps", MAX_DISTANCE),
+        ));
+    }
+    let direction: Direction = direction
+        .parse()
+        .map_err(|_| Error::at(command, direction, "expected U, R, D or L"))?;
+    Ok(vec![direction; distance])
+}
+
+// `None` once the head would leave the coordinates `i32` can hold, which
+// enough long motions the same way reach
+fn move_head(move_command: &Direction, head: Coordinates) -> Option<Coordinates> {
+    let step: Coordinates = move_command.step();
+    Some(Point::new(
+        head.x.checked_add(step.x)?,
+        head.y.checked_add(step.y)?,
+    ))
+}
+
+fn move_tail(tail: Coordinates, head: Coordinates) -> Option<Coordinates> {
+    /*
+    If the head is ever two steps directly up, down, left,
+    or right from the tail,
+    the tail must also move one step in that
+    direction so it remains close enough.
+    Otherwise, if they aren't touching, the tail moves
+    one step diagonally to keep up.
+    */
+    match head.chebyshev(tail) {
+        0 | 1 => Some(tail),
+        2 => Some(tail.step_towards(head)),
+        // the tail fell behind, which a single step of the head cannot do
+        _ => None,
+    }
+}
+
+/// A rope pulled by its head, one step of the motions per tick.
+#[derive(Debug, Clone)]
+struct Rope<'a> {
+    knots: Vec<Coordinates>,
+    motions: &'a [Direction],
+    // a knot ended up out of reach of the one it follows, or the head out
+    // of the plane
+    broken: bool,
+}
+
+impl State for Rope<'_> {
+    fn step(&mut self) -> bool {
+        let Some((motion, motions)) = self.motions.split_first() else {
+            return false;
+        };
+        if self.broken {
+            return false;
+        }
+        self.motions = motions;
+        let Some(head) = move_head(motion, self.knots[0]) else {
+            self.broken = true;
+            return true;
+        };
+        self.knots[0] = head;
+        for i in 1..self.knots.len() {
+            match move_tail(self.knots[i], self.knots[i - 1]) {
+                Some(knot) => self.knots[i] = knot,
+                None => self.broken = true,
+            }
+        }
+        true
+    }
+}
+
+// how many positions the last of `knots` knots visits
+fn tail_visits(input: &[Direction], knots: usize) -> Option<i32> {
+    let mut tail_visited_cells = HashSet::from([Point::ORIGIN]);
+    let rope = Rope {
+        knots: vec![Point::ORIGIN; knots],
+        motions: input,
+        broken: false,
+    };
+    let mut simulation = Simulation::new(rope).observe(|_, rope: &Rope| {
+        tail_visited_cells.insert(rope.knots[knots - 1]);
+    });
+    simulation.run();
+    let broken = simulation.state().broken;
+    drop(simulation);
+    (!broken).then_some(tail_visited_cells.len() as i32)
+}
+
+fn part_one(input: &[Direction]) -> Option<i32> {
+    tail_visits(input, 2)
+}
+
+fn part_two(input: &[Direction]) -> Option<i32> {
+    tail_visits(input, 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_part_one() {
+        let input_str = "R 4\n\
+                        U 4\n\
+                        L 3\n\
+                        D 1\n\
+                        R 4\n\
+                        D 1\n\
+                        L 5\n\
+                        R 2\n";
+        let commands: Vec<Direction> = input_str
+            .lines()
+            .flat_map(|line| parse_command(line).unwrap())
+            .collect();
+        assert_eq!(part_one(&commands).unwrap(), 13);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input_str = "R 4\n\
+                        U 4\n\
+                        L 3\n\
+                        D 1\n\
+                        R 4\n\
+                        D 1\n\
+                        L 5\n\
+                        R 2\n";
+        let commands: Vec<Direction> = input_str
+            .lines()
+            .flat_map(|line| parse_command(line).unwrap())
+            .collect();
+        assert_eq!(part_two(&commands).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_off_the_plane() {
+        let edge = Point::new(i32::MAX, 0);
+        assert_eq!(move_head(&Direction::Right, edge), None);
+        assert_eq!(
+            move_head(&Direction::Left, edge),
+            Some(Point::new(i32::MAX - 1, 0))
+        );
+
+        let mut rope = Rope {
+            knots: vec![edge; 2],
+            motions: &[Direction::Right],
+            broken: false,
+        };
+        assert!(rope.step());
+        assert!(rope.broken);
+        assert!(!rope.step());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        match Day9::parse("R 4\nU 4\nX 3\n").unwrap_err() {
+            Error::Parse(err) => {
+                assert_eq!((err.line, err.column, err.span()), (3, 1, "X"));
+                assert_eq!(err.message, "expected U, R, D or L");
+            }
+            err => panic!("unexpected error: {}", err),
+        }
+        assert!(Day9::parse("R -4").is_err());
+        assert!(parse_command("R 65537").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_parse_command_round_trip(
+            direction in prop::sample::select(Direction::ALL.to_vec()),
+            distance in 0..=MAX_DISTANCE,
+        ) {
+            let steps = parse_command(&format!("{} {}", direction, distance)).unwrap();
+            prop_assert_eq!(steps, vec![direction; distance]);
+        }
+
+        #[test]
+        fn test_parse_command_never_panics(input in "[URDLX]? ?-?[0-9]{0,25}") {
+            let _ = parse_command(&input);
+        }
+    }
+}